@@ -6,8 +6,13 @@
 //! # Details
 //! Detailed documentation, including descriptions of subcommands and their options, is provided for clarity.
 
-use crate::git::branch::BranchRelationStrategy;
-use clap::{Parser, Subcommand, ValueEnum};
+use crate::configuration::defaults::{parse_default_flag, parse_default_strategy};
+use crate::error::{GitFlowError, Result};
+use crate::git::branch::{BranchRelationStrategy, BranchSortOrder};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use std::collections::HashMap;
+use std::io::IsTerminal;
+use std::path::PathBuf;
 
 /// GitFlow CLI for managing GitHub development workflow
 #[derive(Debug, Parser)]
@@ -22,6 +27,34 @@ pub struct Cli {
     #[clap(short, long, action = clap::ArgAction::Count)]
     pub verbose: u8,
 
+    /// Answer "no" to every confirmation prompt (useful for auditing plans in CI)
+    #[clap(long, global = true)]
+    pub assume_no: bool,
+
+    /// Fail immediately if another git process holds the index lock instead of retrying
+    #[clap(long, global = true)]
+    pub no_wait: bool,
+
+    /// When to color output
+    #[clap(
+        long,
+        global = true,
+        value_enum,
+        value_name = "WHEN",
+        default_value_t = ColorChoice::Auto
+    )]
+    pub color: ColorChoice,
+
+    /// How to report a failure: a log line, or a JSON object on stderr for scripts
+    #[clap(
+        long,
+        global = true,
+        value_enum,
+        value_name = "FORMAT",
+        default_value_t = ErrorFormat::Human
+    )]
+    pub error_format: ErrorFormat,
+
     #[clap(subcommand)]
     pub command: Commands,
 }
@@ -34,47 +67,593 @@ pub enum Commands {
         /// Name of the new branch
         name: String,
 
-        /// Parent branch to use (defaults to current branch)
+        /// Parent branch to use, local or remote like origin/main (defaults to current branch)
         #[clap(long)]
         parent: Option<String>,
+
+        /// Infer the parent from the nearest ancestor branch instead of using the current branch
+        #[clap(long, conflicts_with = "parent")]
+        parent_auto: bool,
+
+        /// Do not run the configured create hooks
+        #[clap(long)]
+        no_hooks: bool,
+
+        /// Check that the branch can be created and print what would be done, without doing it
+        #[clap(long)]
+        dry_run: bool,
     },
 
     /// Merge parent branches into child branches recursively
-    Cascade {
-        /// Skip confirmation prompt
+    Cascade(CascadeArgs),
+
+    /// Show the branch structure with PR information
+    Show(ShowArgs),
+
+    /// Show the branch structure under several detection strategies and where they differ
+    Compare {
+        /// Strategies to compare (defaults to all of them)
+        #[clap(value_enum)]
+        strategies: Vec<BranchDetectionStrategy>,
+
+        /// Draw the trees with ASCII glyphs instead of box-drawing characters
         #[clap(long)]
-        yes: bool,
+        ascii: bool,
 
-        /// Strategy for detecting branch relationships
-        #[clap(long, value_enum)]
-        strategy: Option<BranchDetectionStrategy>,
+        /// Only detect relationships between branches matching this glob pattern (can be repeated)
+        #[clap(long)]
+        only: Vec<String>,
     },
 
-    /// Show the branch structure with PR information
-    Show {
-        /// Strategy for detecting branch relationships
-        #[clap(long, value_enum)]
-        strategy: Option<BranchDetectionStrategy>,
+    /// Commit changes on the current branch
+    Commit(CommitArgs),
+
+    /// Preview the files that would conflict when merging one branch into another
+    Preview {
+        /// Source branch
+        from: String,
+
+        /// Target branch
+        to: String,
     },
 
-    /// Configure default settings
-    Config {
-        /// Set the default base branch
+    /// Push branches to their remotes without touching PRs
+    Push {
+        /// Push every branch that has an upstream or matching remote branch
         #[clap(long)]
-        default_base: Option<String>,
+        all: bool,
 
-        /// Set the default branch detection strategy
-        #[clap(long, value_enum)]
-        detection_strategy: Option<BranchDetectionStrategy>,
+        /// Branch to push (defaults to current branch)
+        #[clap(long, conflicts_with = "all")]
+        branch: Option<String>,
+
+        /// Overwrite remote branches that have diverged from the local branch
+        #[clap(long, conflicts_with = "rebase")]
+        force: bool,
+
+        /// Like --force, but refuse if a remote branch moved since it was last fetched
+        #[clap(long, conflicts_with_all = ["force", "rebase"])]
+        force_with_lease: bool,
+
+        /// Rebase diverged branches onto their remote branch before pushing
+        #[clap(long)]
+        rebase: bool,
+    },
+
+    /// Show the changes a branch introduces over its parent
+    Diff {
+        /// Branch to diff (defaults to current branch)
+        #[clap(long)]
+        branch: Option<String>,
+
+        /// Base branch to compare against (defaults to the detected parent)
+        #[clap(long)]
+        base: Option<String>,
+
+        /// Show a diffstat summary instead of the patch
+        #[clap(long)]
+        stat: bool,
+    },
+
+    /// Rebase a branch onto a new parent and update its recorded relationship
+    Reparent {
+        /// The new parent branch
+        new_parent: String,
+
+        /// Branch to move (defaults to current branch)
+        #[clap(long)]
+        branch: Option<String>,
+    },
+
+    /// Set a branch's description (shown next to the branch by 'show')
+    Describe {
+        /// Description text
+        text: String,
+
+        /// Branch to describe (defaults to current branch)
+        #[clap(long)]
+        branch: Option<String>,
+    },
+
+    /// Check and repair the repository setup (e.g. a missing origin/HEAD)
+    Doctor,
+
+    /// Abort a merge or rebase left in progress by an interrupted operation
+    Abort,
 
-        /// Add a manual branch relationship (format: parent:child)
+    /// Check out a branch picked from a numbered list of local branches
+    Switch {
+        /// Only list branches matching this fuzzy query
+        query: Option<String>,
+    },
+
+    /// Tag the tip of a branch
+    Tag {
+        /// Tag name
+        name: String,
+
+        /// Tag message (creates an annotated tag; lightweight if omitted)
+        #[clap(short, long)]
+        message: Option<String>,
+
+        /// Branch to tag (defaults to current branch)
+        #[clap(long)]
+        branch: Option<String>,
+
+        /// Push the tag to the remote
+        #[clap(long)]
+        push: bool,
+
+        /// Overwrite an existing tag
         #[clap(long)]
-        add_relationship: Option<String>,
+        force: bool,
+    },
+
+    /// Commit all changes on the current branch and push it, as a quick checkpoint
+    Save {
+        /// Commit message
+        #[clap(short, long)]
+        message: String,
+
+        /// Push the current branch after committing
+        #[clap(long, default_value_t = true, action = clap::ArgAction::Set, value_name = "BOOL")]
+        push: bool,
+    },
+
+    /// List local branches with no upstream, no tracked PR and no recent commits
+    Stale {
+        /// Minimum age in days of the branch tip
+        #[clap(long, default_value_t = 30)]
+        days: u64,
+    },
+
+    /// Show the changed files in the working tree
+    Status {
+        /// Only show changes under this path (can be repeated)
+        #[clap(long = "path", value_name = "DIR")]
+        paths: Vec<String>,
+    },
+
+    /// Open a branch on the repository's hosting site in the browser
+    Open {
+        /// Branch to open (defaults to current branch)
+        branch: Option<String>,
 
-        /// Remove a manual branch relationship (format: parent:child)
+        /// Open the comparison of the branch against its parent instead
         #[clap(long)]
-        remove_relationship: Option<String>,
+        compare: bool,
+    },
+
+    /// Print the detected parent of a branch (the default base branch if none is detected)
+    Parent {
+        /// Branch whose parent is printed
+        branch: String,
+
+        /// Strategy for detecting branch relationships
+        #[clap(long, value_enum)]
+        strategy: Option<BranchDetectionStrategy>,
     },
+
+    /// Check the branch hierarchy for cycles and the configured branches for existence
+    Validate {
+        /// Print the problems as text or as JSON
+        #[clap(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+
+    /// Configure default settings
+    Config(Box<ConfigArgs>),
+}
+
+/// Options for the 'show' subcommand
+#[derive(Debug, Default, Args)]
+pub struct ShowArgs {
+    /// Strategy for detecting branch relationships
+    #[clap(long, value_enum)]
+    pub strategy: Option<BranchDetectionStrategy>,
+
+    /// Recompute the branch tree even if a cached one is available
+    #[clap(long)]
+    pub no_cache: bool,
+
+    /// Include branches matched by the configured ignore patterns
+    #[clap(long)]
+    pub include_ignored: bool,
+
+    /// Draw the tree with ASCII glyphs instead of box-drawing characters
+    #[clap(long)]
+    pub ascii: bool,
+
+    /// Write the branch structure to this file instead of printing it
+    #[clap(long)]
+    pub output: Option<PathBuf>,
+
+    /// List each branch with its parent ("branch: parent") instead of drawing the tree
+    #[clap(long)]
+    pub parents: bool,
+
+    /// Only detect relationships between branches matching this glob pattern (can be repeated)
+    #[clap(long)]
+    pub only: Vec<String>,
+
+    /// Annotate each branch with the abbreviated hash of its tip commit
+    #[clap(long)]
+    pub hashes: bool,
+
+    /// Order in which the children of each branch are listed (defaults to the configured one)
+    #[clap(long, value_enum)]
+    pub sort: Option<BranchSort>,
+}
+
+/// Options for the 'commit' subcommand
+#[derive(Debug, Default, Args)]
+pub struct CommitArgs {
+    /// Commit message (opens the editor, prefilled with commit.template, if omitted)
+    #[clap(short, long)]
+    pub message: Option<String>,
+
+    /// Only stage changes to tracked files (skip untracked files)
+    #[clap(long, conflicts_with = "staged")]
+    pub tracked_only: bool,
+
+    /// Commit exactly what is already staged, without staging anything
+    #[clap(long, conflicts_with = "paths")]
+    pub staged: bool,
+
+    /// Only stage the given paths (can be repeated)
+    #[clap(long = "path")]
+    pub paths: Vec<String>,
+
+    /// Add a Signed-off-by trailer using the commit author
+    #[clap(long)]
+    pub signoff: bool,
+
+    /// Never stage paths matching this glob pattern (can be repeated)
+    #[clap(long)]
+    pub exclude: Vec<String>,
+
+    /// Replace the last commit, keeping its message unless --message is given
+    #[clap(long)]
+    pub amend: bool,
+
+    /// Conventional commit type prepended to the message (e.g. feat gives "feat: ...")
+    #[clap(long = "type", value_name = "TYPE")]
+    pub commit_type: Option<String>,
+
+    /// Conventional commit scope, added to the type (e.g. "feat(api): ...")
+    #[clap(long, requires = "commit_type")]
+    pub scope: Option<String>,
+}
+
+/// Options for the 'cascade' subcommand
+#[derive(Debug, Default, Args)]
+pub struct CascadeArgs {
+    /// Skip confirmation prompt
+    #[clap(long)]
+    pub yes: bool,
+
+    /// Strategy for detecting branch relationships
+    #[clap(long, value_enum)]
+    pub strategy: Option<BranchDetectionStrategy>,
+
+    /// Recompute the branch tree even if a cached one is available
+    #[clap(long)]
+    pub no_cache: bool,
+
+    /// Fetch all remotes and fast-forward the cascaded branches to their upstreams first
+    #[clap(long, conflicts_with_all = ["resume", "plan_in"])]
+    pub fetch_first: bool,
+
+    /// Only cascade into the descendants of this branch
+    #[clap(long, conflicts_with_all = ["combine", "plan_in"])]
+    pub from: Option<String>,
+
+    /// Write the merge plan as JSON to this path and exit without merging
+    #[clap(long, conflicts_with_all = ["plan_in", "combine", "finalize"])]
+    pub plan_out: Option<PathBuf>,
+
+    /// Execute a previously written merge plan, provided no branch has moved since
+    #[clap(long, conflicts_with_all = ["combine", "strategy", "no_cache", "finalize"])]
+    pub plan_in: Option<PathBuf>,
+
+    /// After cascading, merge each ready leaf branch into the default base branch
+    #[clap(long, conflicts_with = "combine")]
+    pub finalize: bool,
+
+    /// Merge these branches (comma-separated) into --into with a single merge commit
+    #[clap(long, value_delimiter = ',', requires = "into")]
+    pub combine: Vec<String>,
+
+    /// Target branch for --combine
+    #[clap(long, requires = "combine")]
+    pub into: Option<String>,
+
+    /// Continue an interrupted cascade after fixing the merge that stopped it
+    #[clap(
+        long,
+        conflicts_with_all = ["strategy", "no_cache", "from", "plan_out", "plan_in", "combine"]
+    )]
+    pub resume: bool,
+
+    /// Discard the progress of an interrupted cascade
+    #[clap(long, conflicts_with_all = ["resume", "finalize", "plan_out", "plan_in", "combine"])]
+    pub abort: bool,
+
+    /// Only detect relationships between branches matching this glob pattern (can be repeated)
+    #[clap(long, conflicts_with_all = ["resume", "plan_in", "combine", "abort"])]
+    pub only: Vec<String>,
+
+    /// Write the failed merges and their conflicting files as JSON to this path
+    #[clap(long, conflicts_with_all = ["plan_out", "plan_in", "combine", "abort"])]
+    pub report: Option<PathBuf>,
+}
+
+/// Options for the 'config' subcommand
+#[derive(Debug, Default, Args)]
+#[clap(group(
+    ArgGroup::new("relationship_edit")
+        .args(["add_relationship", "remove_relationship"])
+        .multiple(true)
+))]
+pub struct ConfigArgs {
+    /// Set the default base branch
+    #[clap(long)]
+    pub default_base: Option<String>,
+
+    /// Set the default branch detection strategy
+    #[clap(long, value_enum)]
+    pub detection_strategy: Option<BranchDetectionStrategy>,
+
+    /// Auto-select DefaultRoot above this many local branches (CommitHistory otherwise); only
+    /// applies while the detection strategy is the default CommitHistory
+    #[clap(long)]
+    pub auto_strategy_threshold: Option<usize>,
+
+    /// Only follow first parents in history-based detection (ignores merged-in history)
+    #[clap(long)]
+    pub history_first_parent: Option<bool>,
+
+    /// Resolve a branch's parent as the ancestor branch with the fewest commits in between
+    #[clap(long)]
+    pub closest_parent: Option<bool>,
+
+    /// Cache detected branch trees until a branch tip changes
+    #[clap(long)]
+    pub tree_cache: Option<bool>,
+
+    /// Add a manual branch relationship (format: parent:child)
+    #[clap(long)]
+    pub add_relationship: Option<String>,
+
+    /// Remove a manual branch relationship (format: parent:child)
+    #[clap(long)]
+    pub remove_relationship: Option<String>,
+
+    /// Store --add-relationship/--remove-relationship in the repository instead of the config
+    #[clap(long, requires = "relationship_edit")]
+    pub shared: bool,
+
+    /// Also read manual relationships shared in the repository (refs/gitflow/relationships)
+    #[clap(long)]
+    pub shared_relationships: Option<bool>,
+
+    /// Add a branch alias accepted wherever a branch name is given (format: alias:branch)
+    #[clap(long)]
+    pub add_branch_alias: Option<String>,
+
+    /// Rename a branch throughout the configuration (format: old:new)
+    #[clap(long)]
+    pub rename_branch: Option<String>,
+
+    /// Exclude branches matching a glob pattern from detection (e.g. "dependabot/*")
+    #[clap(long)]
+    pub add_ignore_branch: Option<String>,
+
+    /// Stop excluding branches matching a glob pattern
+    #[clap(long)]
+    pub remove_ignore_branch: Option<String>,
+
+    /// Only detect relationships between branches matching a glob pattern (e.g. "feature/*")
+    #[clap(long)]
+    pub add_include_branch: Option<String>,
+
+    /// Stop restricting detection to branches matching a glob pattern
+    #[clap(long)]
+    pub remove_include_branch: Option<String>,
+
+    /// Always draw the branch hierarchy with ASCII glyphs
+    #[clap(long)]
+    pub ascii_tree: Option<bool>,
+
+    /// Set the order in which 'show' lists the children of each branch
+    #[clap(long, value_enum)]
+    pub show_sort: Option<BranchSort>,
+
+    /// Set the regex used to extract ticket keys from branch names
+    #[clap(long)]
+    pub ticket_pattern: Option<String>,
+
+    /// Map a ticket key to a base branch (format: ticket:base)
+    #[clap(long)]
+    pub add_ticket_base: Option<String>,
+
+    /// Add a trailer appended to commit messages (e.g. "Team: platform")
+    #[clap(long)]
+    pub add_commit_trailer: Option<String>,
+
+    /// Accept a conventional commit type in 'commit --type' (extends the defaults)
+    #[clap(long, value_name = "TYPE")]
+    pub add_commit_type: Option<String>,
+
+    /// Stop accepting a conventional commit type in 'commit --type'
+    #[clap(long, value_name = "TYPE")]
+    pub remove_commit_type: Option<String>,
+
+    /// Never stage paths matching a glob pattern when committing (e.g. "*.local.json")
+    #[clap(long)]
+    pub add_commit_exclude: Option<String>,
+
+    /// Stop excluding paths matching a glob pattern when committing
+    #[clap(long)]
+    pub remove_commit_exclude: Option<String>,
+
+    /// Set the rename detection similarity threshold (0-100) used when merging
+    #[clap(long)]
+    pub merge_rename_threshold: Option<u32>,
+
+    /// List the merged commits in the body of merge commits
+    #[clap(long)]
+    pub merge_include_commit_list: Option<bool>,
+
+    /// Date merge commits with the merged commits' author date instead of the current time
+    #[clap(long)]
+    pub preserve_author_date: Option<bool>,
+
+    /// Merge into branches that are not checked out without checking them out (faster on large
+    /// repositories)
+    #[clap(long)]
+    pub in_memory_merge: Option<bool>,
+
+    /// Resolve recurring merge conflicts with the resolutions recorded by `git rerere`; only
+    /// used in repositories that already have .git/rr-cache, which gitflow does not create
+    /// because it also turns on git's own rerere for manual merges
+    #[clap(long)]
+    pub use_rerere: Option<bool>,
+
+    /// Set a standard merge flag (format: flag=value, e.g. ignore_whitespace=true)
+    #[clap(long)]
+    pub set_merge_option: Option<String>,
+
+    /// Set the deepest branch hierarchy level that cascade follows and show prints
+    #[clap(long)]
+    pub max_depth: Option<usize>,
+
+    /// Set how many seconds fetch and push wait for the remote before failing
+    #[clap(long, value_name = "SECS")]
+    pub git_network_timeout_secs: Option<u64>,
+
+    /// Set how often fetch and push are retried after transient network errors
+    #[clap(long, value_name = "COUNT")]
+    pub git_network_retries: Option<u32>,
+
+    /// Run this shell command after each successful cascade merge (GITFLOW_FROM and GITFLOW_TO
+    /// name the branches). It runs with your privileges on every cascade
+    #[clap(long, value_name = "COMMAND")]
+    pub post_merge_command: Option<String>,
+
+    /// Stop the cascade when the post-merge command fails instead of only warning
+    #[clap(long)]
+    pub post_merge_abort_on_failure: Option<bool>,
+
+    /// Add a shell command run after 'create' makes a branch (GITFLOW_BRANCH and GITFLOW_PARENT
+    /// name the branches). It runs with your privileges on every create
+    #[clap(long, value_name = "COMMAND")]
+    pub add_create_hook: Option<String>,
+
+    /// Remove a create hook, given exactly as it was added
+    #[clap(long, value_name = "COMMAND")]
+    pub remove_create_hook: Option<String>,
+
+    /// Run the remaining create hooks after one fails instead of stopping
+    #[clap(long)]
+    pub create_hooks_continue_on_failure: Option<bool>,
+
+    /// Reset a setting to its default (e.g. default_base_branch, branch_relationships)
+    #[clap(long, value_name = "KEY")]
+    pub unset: Option<String>,
+
+    /// Replace the whole configuration with the defaults (asks for confirmation)
+    #[clap(long)]
+    pub reset: bool,
+
+    /// Write the whole configuration to a file, e.g. to share it with a team
+    #[clap(long, value_name = "PATH")]
+    pub export: Option<PathBuf>,
+
+    /// Merge the settings in a file written by --export into the configuration
+    #[clap(long, value_name = "PATH")]
+    pub import: Option<PathBuf>,
+
+    /// With --import, reset settings missing from the file instead of keeping them
+    #[clap(long, requires = "import")]
+    pub replace: bool,
+
+    /// Print the effective configuration as JSON, with the file it was loaded from
+    #[clap(long)]
+    pub effective: bool,
+
+    /// Set a default for a command flag (format: command.flag=value, e.g. cascade.yes=true)
+    #[clap(long)]
+    pub set_command_default: Option<String>,
+}
+
+/// When to color output
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ColorChoice {
+    /// Always color output
+    Always,
+    /// Color output only when stdout is a terminal
+    Auto,
+    /// Never color output
+    Never,
+}
+
+impl ColorChoice {
+    /// Decide whether output should be colored.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - True for `always`, false for `never`, and whether stdout is a terminal for `auto`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // colored::control::set_override(cli.color.enabled());
+    /// ```
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Auto => std::io::stdout().is_terminal(),
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+/// How a failure is reported
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ErrorFormat {
+    /// Log the error for humans
+    Human,
+    /// Print `{"error_kind", "message", "exit_code"}` as JSON to stderr
+    Json,
+}
+
+/// Output formats of reports meant for both people and scripts
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// Plain text, one line per entry
+    Text,
+    /// JSON
+    Json,
 }
 
 /// Command-line friendly enum for branch detection strategies
@@ -100,3 +679,120 @@ impl From<BranchDetectionStrategy> for BranchRelationStrategy {
         }
     }
 }
+
+impl From<BranchRelationStrategy> for BranchDetectionStrategy {
+    fn from(strategy: BranchRelationStrategy) -> Self {
+        match strategy {
+            BranchRelationStrategy::CommitHistory => BranchDetectionStrategy::History,
+            BranchRelationStrategy::CreationTime => BranchDetectionStrategy::Time,
+            BranchRelationStrategy::DefaultRoot => BranchDetectionStrategy::Default,
+            BranchRelationStrategy::Manual => BranchDetectionStrategy::Manual,
+        }
+    }
+}
+
+/// Command-line friendly enum for the order of sibling branches
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum BranchSort {
+    /// Alphabetically by branch name
+    Alpha,
+    /// Most recent tip commit first
+    CommitDate,
+    /// Fewest commits ahead of the parent first
+    Topo,
+}
+
+impl From<BranchSort> for BranchSortOrder {
+    fn from(sort: BranchSort) -> Self {
+        match sort {
+            BranchSort::Alpha => BranchSortOrder::Alpha,
+            BranchSort::CommitDate => BranchSortOrder::CommitDate,
+            BranchSort::Topo => BranchSortOrder::Topo,
+        }
+    }
+}
+
+impl Commands {
+    /// Apply per-command defaults from the configuration to flags not given on the command line.
+    ///
+    /// A default can only switch a boolean flag on and only fills options that were not given,
+    /// so explicit command line flags always win.
+    ///
+    /// # Arguments
+    ///
+    /// * `defaults`  - Default flag values keyed by command name, then by flag name.
+    /// * `assume_no` - Whether `--assume-no` was given, which overrides a default `--yes`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if a default is unknown or invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // cli.command.apply_defaults(&config.command_defaults, cli.assume_no)?;
+    /// ```
+    pub fn apply_defaults(
+        &mut self,
+        defaults: &HashMap<String, HashMap<String, String>>,
+        assume_no: bool,
+    ) -> Result<()> {
+        let command = match self {
+            Commands::Cascade(_) => "cascade",
+            Commands::Show(_) => "show",
+            Commands::Commit(_) => "commit",
+            Commands::Push { .. } => "push",
+            Commands::Tag { .. } => "tag",
+            _ => return Ok(()),
+        };
+        let Some(values) = defaults.get(command) else {
+            return Ok(());
+        };
+
+        for (flag, value) in values {
+            let enabled = || parse_default_flag(command, flag, value);
+            match (&mut *self, flag.as_str()) {
+                (Commands::Cascade(args), "yes") => args.yes |= enabled()? && !assume_no,
+                (Commands::Cascade(args), "no-cache") => args.no_cache |= enabled()?,
+                (Commands::Cascade(args), "fetch-first") => {
+                    args.fetch_first |= enabled()? && !args.resume && args.plan_in.is_none()
+                }
+                (Commands::Cascade(args), "finalize") => args.finalize |= enabled()?,
+                (Commands::Cascade(args), "strategy") => {
+                    if args.strategy.is_none() {
+                        args.strategy = Some(parse_default_strategy(command, value)?.into());
+                    }
+                }
+                (Commands::Show(args), "no-cache") => args.no_cache |= enabled()?,
+                (Commands::Show(args), "include-ignored") => args.include_ignored |= enabled()?,
+                (Commands::Show(args), "ascii") => args.ascii |= enabled()?,
+                (Commands::Show(args), "hashes") => args.hashes |= enabled()?,
+                (Commands::Show(args), "strategy") => {
+                    if args.strategy.is_none() {
+                        args.strategy = Some(parse_default_strategy(command, value)?.into());
+                    }
+                }
+                (Commands::Commit(args), "tracked-only") => {
+                    args.tracked_only |= enabled()? && !args.staged && args.paths.is_empty()
+                }
+                (Commands::Commit(args), "signoff") => args.signoff |= enabled()?,
+                (
+                    Commands::Push {
+                        rebase,
+                        force,
+                        force_with_lease,
+                        ..
+                    },
+                    "rebase",
+                ) => *rebase |= enabled()? && !*force && !*force_with_lease,
+                (Commands::Tag { push, .. }, "push") => *push |= enabled()?,
+                _ => {
+                    return Err(GitFlowError::Config(format!(
+                        "Unknown command default '{}.{}'",
+                        command, flag
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
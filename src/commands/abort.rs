@@ -0,0 +1,36 @@
+//! Module for the 'abort' command.
+//!
+//! This module handles recovering from an interrupted cascade or reparent by aborting the merge
+//! or rebase left in progress and restoring a clean working tree.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::error::Result;
+use crate::git;
+use git2::Repository;
+use log::info;
+
+/// Handle the 'abort' command to abort an in-progress merge or rebase
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the operation cannot be aborted.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_abort(&repo)?;
+/// ```
+pub fn handle_abort(repo: &Repository) -> Result<()> {
+    match git::status::abort_in_progress(repo)? {
+        Some(state) => info!("Aborted in-progress {:?}; the working tree is clean", state),
+        None => info!("No merge or rebase in progress"),
+    }
+    Ok(())
+}
@@ -2,27 +2,33 @@
 //!
 //! This module handles recursively merging branches according to the detected branch hierarchy.
 //! It loads configuration, determines the branch detection strategy, plans the merges, and
-//! prompts the user for confirmation before executing merge operations.
+//! prompts the user for confirmation before executing merge operations. Optionally the leaf
+//! branches are then merged into the default base branch to complete the cascade. The plan can
+//! also be written to a file for review and executed later, and a cascade interrupted by a
+//! failed merge can be resumed once the problem is fixed.
 //!
 //! # Details
 //! Detailed documentation is provided for easier maintenance and clarity.
 
-use crate::cli::BranchDetectionStrategy;
+use crate::cli::CascadeArgs;
 use crate::configuration::Config;
 use crate::error::{GitFlowError, Result};
 use crate::git;
-use crate::utils::prompt_confirmation;
+use crate::git::progress::{self, CascadeState};
+use crate::git::report::{self, FailedMerge};
+use crate::utils::{assume_no, prompt_confirmation};
 use git2::Repository;
 use log::{info, warn};
 use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
 
 /// Handle the 'cascade' command to merge branches recursively
 ///
 /// # Arguments
 ///
-/// * `repo`         - A reference to the Git repository.
-/// * `yes`          - Flag to bypass confirmation prompts.
-/// * `strategy_opt` - Optional branch detection strategy from the CLI.
+/// * `repo` - A reference to the Git repository.
+/// * `args` - The parsed options of the 'cascade' subcommand.
 ///
 /// # Returns
 ///
@@ -32,26 +38,88 @@ use std::collections::HashMap;
 ///
 /// ```rust
 /// // Example usage:
-/// // handle_cascade(&repo, false, Some(BranchDetectionStrategy::Default))?;
+/// // handle_cascade(&repo, &CascadeArgs { yes: true, ..Default::default() })?;
 /// ```
-pub fn handle_cascade(
-    repo: &Repository,
-    yes: bool,
-    strategy_opt: Option<BranchDetectionStrategy>,
-) -> Result<()> {
+pub fn handle_cascade(repo: &Repository, args: &CascadeArgs) -> Result<()> {
     // Load configuration for branch detection strategy.
-    let config = Config::load()?;
+    let mut config = Config::load()?;
+    config.override_include_branches(&args.only)?;
+
+    // Bring remote-tracking branches up to date so the cascade works on fresh data; the local
+    // branches are fast-forwarded once the branches to cascade are known.
+    if args.fetch_first {
+        let fetched = git::fetch_all(repo)?;
+        info!("Fetched {} remote(s)", fetched);
+    }
+
+    for warning in config.validate_against_repo(repo) {
+        warn!("{}", warning);
+    }
+
+    // Forget an interrupted cascade instead of resuming it.
+    if args.abort {
+        if progress::clear_state(repo)? {
+            info!("Discarded the interrupted cascade");
+        } else {
+            info!("No interrupted cascade to discard");
+        }
+        return Ok(());
+    }
+
+    // Continue an interrupted cascade with the hierarchy it was started with.
+    if args.resume {
+        let state = progress::load_state(repo)?
+            .ok_or_else(|| GitFlowError::Aborted("No interrupted cascade to resume".to_string()))?;
+        info!("Resuming cascade; remaining merges:");
+        for (parent, child) in state.pending(repo)? {
+            info!("  {} -> {}", parent, child);
+        }
+        if !args.yes && !prompt_confirmation("Proceed with merges?")? {
+            if assume_no() {
+                info!("Not merging (--assume-no)");
+                return Ok(());
+            }
+            return Err(GitFlowError::Aborted(
+                "Merge operation cancelled".to_string(),
+            ));
+        }
+        return run_cascade(repo, state, args, &config);
+    }
+
+    // A reviewed plan is executed as written instead of detecting the hierarchy again.
+    if let Some(path) = &args.plan_in {
+        return execute_plan(repo, path, args.yes, &config);
+    }
+
+    // Combining explicit sources bypasses the detected hierarchy entirely.
+    if let Some(into) = &args.into {
+        let sources: Vec<String> = args
+            .combine
+            .iter()
+            .map(|name| config.resolve_branch_alias(name))
+            .collect();
+        let into = config.resolve_branch_alias(into);
+        if args.fetch_first {
+            for branch in sources.iter().chain([&into]) {
+                git::remote::fast_forward_to_upstream(repo, branch)?;
+            }
+        }
+        return combine_into(repo, &sources, &into, args.yes, &config);
+    }
+
+    let strategy_opt = args.strategy;
 
     // Determine the branch detection strategy.
     let mut strategy = match strategy_opt {
         Some(s) => s.into(),
-        None => config.branch_detection_strategy,
+        None => git::select_strategy(repo, &config)?,
     };
 
     info!("Using branch detection strategy: {:?}", strategy);
 
     // Retrieve the branch tree using the selected strategy.
-    let mut branch_tree = git::get_branch_tree(repo, strategy, &config)?;
+    let use_cache = config.tree_cache && !args.no_cache;
+    let mut branch_tree = git::get_branch_tree_cached(repo, strategy, &config, use_cache)?;
 
     if branch_tree.is_empty() && strategy_opt.is_none() {
         info!("No branch hierarchy detected with current strategy.");
@@ -73,7 +141,7 @@ pub fn handle_cascade(
             }
 
             strategy = *alt_strategy;
-            branch_tree = git::get_branch_tree(repo, strategy, &config)?;
+            branch_tree = git::get_branch_tree_cached(repo, strategy, &config, use_cache)?;
 
             if !branch_tree.is_empty() {
                 info!("Found branch hierarchy with {:?} strategy!", strategy);
@@ -89,32 +157,384 @@ pub fn handle_cascade(
     }
 
     if branch_tree.is_empty() {
-        info!("No branch hierarchy detected with any strategy. Try setting up manual relationships.");
+        info!(
+            "No branch hierarchy detected with any strategy. Try setting up manual relationships."
+        );
         return Ok(());
     }
 
+    // Limit the cascade to the stack below the requested branch.
+    if let Some(from) = &args.from {
+        let from = config.resolve_branch_alias(from);
+        branch_tree = git::get_subtree(&branch_tree, &from).ok_or_else(|| {
+            GitFlowError::BranchNotFound(format!("{} (not in the branch hierarchy)", from))
+        })?;
+        if branch_tree.is_empty() {
+            info!("{} has no descendants to cascade into", from);
+            return Ok(());
+        }
+    }
+
+    // The merges run on local branches, so bring them up to the fetched upstreams too.
+    if args.fetch_first {
+        for branch in git::topological_order(&branch_tree) {
+            git::remote::fast_forward_to_upstream(repo, &branch)?;
+        }
+    }
+
     // Display the planned merge operations.
     info!("Planning to perform the following merges:");
     for (parent, children) in &branch_tree {
         for child in children {
             info!("  {} -> {}", parent, child);
+            // Flag merges that will not apply cleanly before anything is touched.
+            if let Ok(conflicts) = git::preview_conflicts(repo, parent, child, &config)
+                && !conflicts.is_empty()
+            {
+                warn!("    conflicts in: {}", conflicts.join(", "));
+            }
         }
     }
 
+    if let Some(path) = &args.plan_out {
+        let plan = git::plan::build_plan(repo, &branch_tree)?;
+        git::plan::write_plan(&plan, path)?;
+        info!(
+            "Wrote {} planned merge(s) to {}",
+            plan.operations.len(),
+            path.display()
+        );
+        return Ok(());
+    }
+
     // Confirm execution unless the '--yes' flag is provided.
-    if !yes && !prompt_confirmation("Proceed with merges?")? {
-        return Err(GitFlowError::Aborted("Merge operation cancelled".to_string()));
+    if !args.yes && !prompt_confirmation("Proceed with merges?")? {
+        // Declining via --assume-no is an audit of the plan, not a failure.
+        if assume_no() {
+            info!("Not merging (--assume-no)");
+            return Ok(());
+        }
+        return Err(GitFlowError::Aborted(
+            "Merge operation cancelled".to_string(),
+        ));
+    }
+
+    if progress::load_state(repo)?.is_some() {
+        warn!("Replacing the progress of an interrupted cascade (use --resume to continue it)");
     }
+    run_cascade(repo, CascadeState::new(branch_tree), args, &config)
+}
+
+/// Run the merges of a cascade that are not completed yet, recording progress as it goes.
+///
+/// Progress is saved after every merge. It is discarded once every merge has completed, and
+/// kept otherwise so that the cascade can be resumed with `--resume`. The failed merges are
+/// written to the `--report` file, if one was given, even when the cascade stops early.
+///
+/// # Arguments
+///
+/// * `repo`   - The Git repository.
+/// * `state`  - The branch tree to cascade and the merges already completed.
+/// * `args`   - The parsed options of the 'cascade' subcommand.
+/// * `config` - Configuration providing the merge options.
+///
+/// # Returns
+///
+/// * `Result<()>`   - Ok on success, or an error if the cascade cannot continue.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // run_cascade(&repo, CascadeState::new(branch_tree), &args, &config)?;
+/// ```
+fn run_cascade(
+    repo: &Repository,
+    mut state: CascadeState,
+    args: &CascadeArgs,
+    config: &Config,
+) -> Result<()> {
+    progress::save_state(repo, &state)?;
 
     let mut processed = HashMap::new();
+    let mut failures = Vec::new();
+
+    // Recursively process each root branch. An error stops the cascade, but only after the
+    // failures seen so far are reported.
+    let root_branches = git::find_root_branches(&state.tree);
+    let result = root_branches.iter().try_for_each(|branch| {
+        merge_recursive(
+            repo,
+            branch,
+            &mut state,
+            config,
+            &mut processed,
+            &mut failures,
+            0,
+        )
+    });
+
+    if let Some(path) = &args.report {
+        report::write_report(&failures, path)?;
+        info!(
+            "Wrote {} failed merge(s) to {}",
+            failures.len(),
+            path.display()
+        );
+    }
+    result?;
+
+    let pending = state.pending(repo)?.len();
+    if pending == 0 {
+        progress::clear_state(repo)?;
+        info!("Cascade merge completed successfully");
+    } else {
+        warn!(
+            "{} merge(s) did not complete; fix them and run 'gitflow cascade --resume'",
+            pending
+        );
+    }
+
+    if args.finalize {
+        finalize(repo, &state.tree, args.yes, config)?;
+    }
+    Ok(())
+}
+
+/// Merge each ready leaf branch of the hierarchy into the default base branch.
+///
+/// A leaf is ready when it already contains its parent, is not yet part of the default base
+/// branch, and merges into it without conflicts.
+///
+/// # Arguments
+///
+/// * `repo`        - The Git repository.
+/// * `branch_tree` - Mapping from parent branches to child branches.
+/// * `yes`         - Flag to bypass the confirmation prompt.
+/// * `config`      - Configuration providing the default base branch and merge options.
+///
+/// # Returns
+///
+/// * `Result<()>`   - Ok on success, or an error if the merges are cancelled.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // finalize(&repo, &branch_tree, false, &config)?;
+/// ```
+fn finalize(
+    repo: &Repository,
+    branch_tree: &HashMap<String, Vec<String>>,
+    yes: bool,
+    config: &Config,
+) -> Result<()> {
+    let base = &config.default_base_branch;
+
+    let mut leaves: Vec<(&String, &String)> = branch_tree
+        .iter()
+        .flat_map(|(parent, children)| children.iter().map(move |child| (parent, child)))
+        .filter(|(_, child)| branch_tree.get(*child).is_none_or(|c| c.is_empty()))
+        .collect();
+    leaves.sort_by_key(|(_, child)| *child);
+    leaves.dedup_by_key(|(_, child)| *child);
+
+    let mut ready = Vec::new();
+    for (parent, leaf) in leaves {
+        if leaf == base || git::is_up_to_date(repo, leaf, base).unwrap_or(false) {
+            continue;
+        }
+        if !git::is_up_to_date(repo, parent, leaf).unwrap_or(false) {
+            warn!(
+                "Not finalizing {}: it does not contain {} yet",
+                leaf, parent
+            );
+            continue;
+        }
+        match git::preview_conflicts(repo, leaf, base, config) {
+            Ok(conflicts) if conflicts.is_empty() => ready.push(leaf.as_str()),
+            Ok(conflicts) => warn!(
+                "Not finalizing {}: conflicts in {}",
+                leaf,
+                conflicts.join(", ")
+            ),
+            Err(e) => warn!("Not finalizing {}: {}", leaf, e),
+        }
+    }
+
+    if ready.is_empty() {
+        info!("No leaf branches are ready to merge into {}", base);
+        return Ok(());
+    }
+
+    info!("Planning to merge into {}:", base);
+    for leaf in &ready {
+        info!("  {} -> {}", leaf, base);
+    }
+    if !yes && !prompt_confirmation(&format!("Merge into {}?", base))? {
+        if assume_no() {
+            info!("Not merging into {} (--assume-no)", base);
+            return Ok(());
+        }
+        return Err(GitFlowError::Aborted(
+            "Merge operation cancelled".to_string(),
+        ));
+    }
+
+    for leaf in ready {
+        git::merge_branch(repo, leaf, base, config)?;
+    }
+    info!("Finalized cascade into {}", base);
+    Ok(())
+}
+
+/// Execute a previously written cascade plan.
+///
+/// The plan is rejected if any of its branches moved since it was generated. Operations run
+/// in the recorded order and the first failure stops execution.
+///
+/// # Arguments
+///
+/// * `repo`   - The Git repository.
+/// * `path`   - The plan file.
+/// * `yes`    - Flag to bypass the confirmation prompt.
+/// * `config` - Configuration providing the merge options.
+///
+/// # Returns
+///
+/// * `Result<()>`   - Ok on success, or an error if the plan is stale or a merge fails.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // execute_plan(&repo, Path::new("plan.json"), false, &config)?;
+/// ```
+fn execute_plan(repo: &Repository, path: &Path, yes: bool, config: &Config) -> Result<()> {
+    let plan = git::plan::read_plan(repo, path)?;
+
+    info!("Executing plan from {}:", path.display());
+    for op in &plan.operations {
+        info!("  {} -> {} ({:?})", op.parent, op.child, op.merge_type);
+    }
+
+    if !yes && !prompt_confirmation("Proceed with merges?")? {
+        if assume_no() {
+            info!("Not merging (--assume-no)");
+            return Ok(());
+        }
+        return Err(GitFlowError::Aborted(
+            "Merge operation cancelled".to_string(),
+        ));
+    }
+
+    for op in &plan.operations {
+        if git::is_up_to_date(repo, &op.parent, &op.child)? {
+            info!("{} is already up to date with {}", op.child, op.parent);
+            continue;
+        }
+        git::merge_branch(repo, &op.parent, &op.child, config)?;
+    }
+
+    info!("Plan executed successfully");
+    Ok(())
+}
+
+/// Merge several branches into a target with a single merge commit.
+///
+/// # Arguments
+///
+/// * `repo`    - The Git repository.
+/// * `sources` - The branches to merge.
+/// * `target`  - The branch receiving the merge.
+/// * `yes`     - Flag to bypass the confirmation prompt.
+/// * `config`  - Configuration providing the merge options.
+///
+/// # Returns
+///
+/// * `Result<()>`   - Ok on success, or an error if the merge conflicts or is cancelled.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // combine_into(&repo, &["a".to_string(), "b".to_string()], "release", false, &config)?;
+/// ```
+fn combine_into(
+    repo: &Repository,
+    sources: &[String],
+    target: &str,
+    yes: bool,
+    config: &Config,
+) -> Result<()> {
+    info!("Planning to merge {} into {}", sources.join(", "), target);
 
-    // Recursively process each root branch.
-    let root_branches = git::find_root_branches(&branch_tree);
-    for branch in root_branches {
-        merge_recursive(repo, &branch, &branch_tree, &mut processed)?;
+    if !yes && !prompt_confirmation("Proceed with merge?")? {
+        if assume_no() {
+            info!("Not merging (--assume-no)");
+            return Ok(());
+        }
+        return Err(GitFlowError::Aborted(
+            "Merge operation cancelled".to_string(),
+        ));
     }
 
-    info!("Cascade merge completed successfully");
+    let sources: Vec<&str> = sources.iter().map(String::as_str).collect();
+    git::merge_branches(repo, &sources, target, config)
+}
+
+/// Run the configured post-merge command after a successful merge.
+///
+/// The command runs through `sh -c` in the repository's working directory, with
+/// `GITFLOW_FROM` and `GITFLOW_TO` set to the merged branches. A failure is reported as a
+/// warning unless `post_merge_abort_on_failure` is set.
+///
+/// # Arguments
+///
+/// * `repo`   - The Git repository.
+/// * `from`   - The branch that was merged.
+/// * `to`     - The branch that received the merge.
+/// * `config` - Configuration providing the command.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok if there is no command or it succeeded (or failed without aborting),
+///   or an Aborted error if it failed and failures abort the cascade.
+///
+/// # Examples
+///
+/// ```rust
+/// // run_post_merge_command(&repo, "main", "feature", &config)?;
+/// ```
+fn run_post_merge_command(repo: &Repository, from: &str, to: &str, config: &Config) -> Result<()> {
+    let Some(command) = &config.post_merge_command else {
+        return Ok(());
+    };
+    info!("Running post-merge command for {} into {}", from, to);
+
+    let mut process = Command::new("sh");
+    process
+        .arg("-c")
+        .arg(command)
+        .env("GITFLOW_FROM", from)
+        .env("GITFLOW_TO", to);
+    if let Some(workdir) = repo.workdir() {
+        process.current_dir(workdir);
+    }
+
+    let failure = match process.status() {
+        Ok(status) if status.success() => return Ok(()),
+        Ok(status) => format!("exited with {}", status),
+        Err(e) => format!("could not be started: {}", e),
+    };
+    let message = format!("Post-merge command for {} into {} {}", from, to, failure);
+    if config.post_merge_abort_on_failure {
+        return Err(GitFlowError::Aborted(format!(
+            "{}; resume with 'gitflow cascade --resume'",
+            message
+        )));
+    }
+    warn!("{}", message);
     Ok(())
 }
 
@@ -124,43 +544,81 @@ pub fn handle_cascade(
 ///
 /// * `repo`         - The Git repository.
 /// * `branch`       - The current branch to process.
-/// * `branch_tree`  - Mapping from parent branches to child branches.
+/// * `state`        - The branch tree and completed merges, updated and saved after each merge.
+/// * `config`       - Configuration providing the merge options.
 /// * `processed`    - A mutable map tracking processed branches to avoid duplication.
+/// * `failures`     - Collects the merges that failed, with their conflicting files.
+/// * `depth`        - Depth of `branch` in the hierarchy, 0 for root branches.
 ///
 /// # Returns
 ///
-/// * `Result<()>`   - Ok on success.
+/// * `Result<()>`   - Ok on success, or an error if the hierarchy is deeper than the configured
+///   maximum depth.
 ///
 /// # Examples
 ///
 /// ```rust
 /// // Example usage:
-/// // merge_recursive(&repo, "main", &branch_tree, &mut HashMap::new())?;
+/// // merge_recursive(&repo, "main", &mut state, &config, &mut HashMap::new(), &mut Vec::new(), 0)?;
 /// ```
 fn merge_recursive(
     repo: &Repository,
     branch: &str,
-    branch_tree: &HashMap<String, Vec<String>>,
+    state: &mut CascadeState,
+    config: &Config,
     processed: &mut HashMap<String, bool>,
+    failures: &mut Vec<FailedMerge>,
+    depth: usize,
 ) -> Result<()> {
     if processed.contains_key(branch) {
         return Ok(());
     }
 
+    // Stop before a pathological hierarchy exhausts the stack.
+    if depth > config.max_depth() {
+        return Err(GitFlowError::Aborted(format!(
+            "Maximum branch hierarchy depth of {} exceeded at {}",
+            config.max_depth(),
+            branch
+        )));
+    }
+
     // Mark this branch as processed.
     processed.insert(branch.to_string(), true);
 
     // For each child branch, merge the current branch and process recursively.
-    if let Some(children) = branch_tree.get(branch) {
-        for child in children {
+    if let Some(children) = state.tree.get(branch).cloned() {
+        for child in &children {
+            // Merges completed before the cascade was interrupted are not repeated, unless
+            // the parent has moved since.
+            let tip = git::plan::branch_tip(repo, branch)?;
+            if state.is_completed(branch, child, &tip) {
+                merge_recursive(repo, child, state, config, processed, failures, depth + 1)?;
+                continue;
+            }
+
+            // Skip children that already contain the parent without checking them out.
+            if git::is_up_to_date(repo, branch, child).unwrap_or(false) {
+                info!("{} is already up to date with {}", child, branch);
+                state.complete(branch, child, &tip);
+                progress::save_state(repo, state)?;
+                merge_recursive(repo, child, state, config, processed, failures, depth + 1)?;
+                continue;
+            }
+
             // Attempt merge of parent branch into child branch.
-            match git::merge_branch(repo, branch, child) {
-                Ok(_) => {},
+            match git::merge_branch(repo, branch, child, config) {
+                Ok(_) => {
+                    state.complete(branch, child, &tip);
+                    progress::save_state(repo, state)?;
+                    run_post_merge_command(repo, branch, child, config)?;
+                }
                 Err(e) => {
                     warn!("Failed to merge {} into {}: {}", branch, child, e);
+                    failures.push(FailedMerge::new(repo, branch, child, &e, config));
                 }
             }
-            merge_recursive(repo, child, branch_tree, processed)?;
+            merge_recursive(repo, child, state, config, processed, failures, depth + 1)?;
         }
     }
 
@@ -0,0 +1,177 @@
+//! Module for the 'commit' command.
+//!
+//! This module handles committing working tree changes on the current branch, letting the
+//! user choose whether untracked files or only specific paths are staged, and which paths
+//! are never staged. The changes can also be folded into the last commit instead.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::cli::CommitArgs;
+use crate::configuration::Config;
+use crate::error::{GitFlowError, Result};
+use crate::git::{self, StageMode};
+use git2::{BranchType, Oid, Repository};
+use log::warn;
+
+/// Handle the 'commit' command to commit changes on the current branch
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+/// * `args` - The parsed options of the 'commit' subcommand. Without a message, the message is
+///   asked for in the editor, or kept from the last commit when amending. `paths` takes
+///   precedence over `tracked_only`, and `exclude` adds to the configured patterns. A
+///   `commit_type` (and `scope`) is prepended to the message as a conventional commit prefix.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if there is nothing to commit or amend.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_commit(&repo, &CommitArgs { message: Some("Fix typo".into()), ..Default::default() })?;
+/// ```
+pub fn handle_commit(repo: &Repository, args: &CommitArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let stage = if args.staged {
+        StageMode::Staged
+    } else if !args.paths.is_empty() {
+        StageMode::Paths(args.paths.clone())
+    } else if args.tracked_only {
+        StageMode::TrackedOnly
+    } else {
+        StageMode::All
+    };
+
+    let mut trailers = config.commit_trailers.clone();
+    if args.signoff {
+        let sig = repo.signature()?;
+        trailers.push(format!(
+            "Signed-off-by: {} <{}>",
+            sig.name().unwrap_or(""),
+            sig.email().unwrap_or("")
+        ));
+    }
+
+    let mut exclude_patterns = config.commit_exclude.clone();
+    exclude_patterns.extend(args.exclude.iter().cloned());
+
+    let prefix = conventional_prefix(args, &config)?;
+    let with_prefix = |message: String| match &prefix {
+        Some(prefix) => format!("{}: {}", prefix, message),
+        None => message,
+    };
+
+    if args.amend {
+        if prefix.is_some() && args.message.is_none() {
+            return Err(GitFlowError::Config(
+                "--type needs --message when amending".to_string(),
+            ));
+        }
+        let message = args.message.clone().map(with_prefix);
+        let previous = repo.head().ok().and_then(|head| head.target());
+        git::amend_commit(
+            repo,
+            message.as_deref(),
+            &stage,
+            &trailers,
+            &exclude_patterns,
+        )?;
+        if let Some(previous) = previous {
+            warn_if_pushed(repo, previous)?;
+        }
+        return Ok(());
+    }
+
+    let message = match args.message.as_deref() {
+        Some(message) => message.to_string(),
+        None => git::edit_commit_message(repo)?,
+    };
+    let message = with_prefix(message);
+
+    git::commit_changes(repo, &message, &stage, &trailers, &exclude_patterns)?;
+    Ok(())
+}
+
+/// Build the conventional commit prefix (`type` or `type(scope)`) requested on the command line.
+///
+/// # Arguments
+///
+/// * `args`   - The parsed options of the 'commit' subcommand.
+/// * `config` - Configuration providing the accepted commit types.
+///
+/// # Returns
+///
+/// * `Result<Option<String>>` - The prefix without the trailing colon, None if no type was
+///   given, or a configuration error if the type is not accepted or the scope is malformed.
+///
+/// # Examples
+///
+/// ```rust
+/// // let prefix = conventional_prefix(&args, &config)?; // Some("feat(api)")
+/// ```
+fn conventional_prefix(args: &CommitArgs, config: &Config) -> Result<Option<String>> {
+    let Some(commit_type) = args.commit_type.as_deref().map(str::trim) else {
+        return Ok(None);
+    };
+    let allowed = config.commit_types();
+    if !allowed.iter().any(|t| t == commit_type) {
+        return Err(GitFlowError::Config(format!(
+            "Unknown commit type '{}' (accepted: {})",
+            commit_type,
+            allowed.join(", ")
+        )));
+    }
+
+    let Some(scope) = args.scope.as_deref().map(str::trim) else {
+        return Ok(Some(commit_type.to_string()));
+    };
+    if scope.is_empty() || scope.contains(['(', ')', ':', '\n']) {
+        return Err(GitFlowError::Config(format!(
+            "Invalid commit scope '{}'",
+            scope
+        )));
+    }
+    Ok(Some(format!("{}({})", commit_type, scope)))
+}
+
+/// Warn when an amended commit had already been pushed, since the amend rewrote it.
+///
+/// # Arguments
+///
+/// * `repo`     - A reference to the Git repository.
+/// * `previous` - The commit that was replaced by the amend.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the current branch cannot be read.
+///
+/// # Examples
+///
+/// ```rust
+/// // warn_if_pushed(&repo, previous_oid)?;
+/// ```
+fn warn_if_pushed(repo: &Repository, previous: Oid) -> Result<()> {
+    let branch_name = git::get_current_branch(repo)?;
+    let Ok(upstream) = repo
+        .find_branch(&branch_name, BranchType::Local)?
+        .upstream()
+    else {
+        return Ok(());
+    };
+    let Some(upstream_tip) = upstream.get().target() else {
+        return Ok(());
+    };
+
+    if upstream_tip == previous || repo.graph_descendant_of(upstream_tip, previous)? {
+        warn!(
+            "The amended commit was already pushed to {}; the next push must overwrite it with --force-with-lease",
+            upstream.name()?.unwrap_or("the upstream")
+        );
+    }
+    Ok(())
+}
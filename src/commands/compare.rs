@@ -0,0 +1,122 @@
+//! Module for the 'compare' command.
+//!
+//! This module handles building the branch hierarchy under several detection strategies and
+//! printing the trees one after another, followed by the relationships the strategies
+//! disagree on, to help choose a default strategy.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::cli::BranchDetectionStrategy;
+use crate::configuration::Config;
+use crate::error::Result;
+use crate::git::{self, BranchRelationStrategy};
+use crate::utils::{BranchDetails, render_branch_hierarchy, select_glyphs};
+use colored::Colorize;
+use git2::Repository;
+use std::collections::BTreeMap;
+
+/// Handle the 'compare' command to show the hierarchy under several strategies
+///
+/// # Arguments
+///
+/// * `repo`      - A reference to the Git repository.
+/// * `requested` - The strategies to compare (all of them if empty).
+/// * `ascii`     - Draw the trees with ASCII glyphs.
+/// * `only`      - Glob patterns replacing the configured include patterns for this run.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if a tree cannot be built.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_compare(&repo, &[BranchDetectionStrategy::History], false, &[])?;
+/// ```
+pub fn handle_compare(
+    repo: &Repository,
+    requested: &[BranchDetectionStrategy],
+    ascii: bool,
+    only: &[String],
+) -> Result<()> {
+    let mut config = Config::load()?;
+    config.override_include_branches(only)?;
+
+    let mut strategies: Vec<BranchRelationStrategy> = Vec::new();
+    for &strategy in requested {
+        if !strategies.contains(&strategy.into()) {
+            strategies.push(strategy.into());
+        }
+    }
+    if strategies.is_empty() {
+        strategies = vec![
+            BranchRelationStrategy::CommitHistory,
+            BranchRelationStrategy::CreationTime,
+            BranchRelationStrategy::DefaultRoot,
+            BranchRelationStrategy::Manual,
+        ];
+    }
+
+    let current_branch = git::get_current_branch(repo)?;
+    let glyphs = select_glyphs(ascii || config.ascii_tree);
+
+    // Which strategies found each (parent, child) relationship.
+    let mut relationships: BTreeMap<(String, String), Vec<BranchRelationStrategy>> =
+        BTreeMap::new();
+
+    for &strategy in &strategies {
+        let tree = git::get_branch_tree(repo, strategy, &config)?;
+        for (parent, children) in &tree {
+            for child in children {
+                relationships
+                    .entry((parent.clone(), child.clone()))
+                    .or_default()
+                    .push(strategy);
+            }
+        }
+
+        println!("{}", format!("== {:?} ==", strategy).bold());
+        if tree.is_empty() {
+            println!("  (no hierarchy detected)");
+        } else {
+            let roots = git::find_root_branches(&tree);
+            print!(
+                "{}",
+                render_branch_hierarchy(
+                    &tree,
+                    &roots,
+                    &current_branch,
+                    &BranchDetails::default(),
+                    &glyphs,
+                    config.max_depth(),
+                )
+            );
+        }
+        println!();
+    }
+
+    // Relationships found by only some of the strategies.
+    let differing: Vec<_> = relationships
+        .iter()
+        .filter(|(_, found_by)| found_by.len() < strategies.len())
+        .collect();
+
+    if differing.is_empty() {
+        println!("{}", "All strategies agree".green());
+        return Ok(());
+    }
+
+    println!("{}", "Relationships that differ:".bold());
+    for ((parent, child), found_by) in differing {
+        let found_by: Vec<String> = found_by.iter().map(|s| format!("{:?}", s)).collect();
+        println!(
+            "  {} -> {} {}",
+            parent,
+            child,
+            format!("(only {})", found_by.join(", ")).yellow()
+        );
+    }
+    Ok(())
+}
@@ -6,19 +6,21 @@
 //! # Details
 //! Enhanced documentation is provided for clearer maintenance and easier future updates.
 
-use crate::cli::BranchDetectionStrategy;
+use crate::cli::ConfigArgs;
 use crate::configuration::Config;
+use crate::configuration::settings::get_config_path;
 use crate::error::{GitFlowError, Result};
-use log::info;
+use crate::git;
+use crate::utils::{assume_no, prompt_confirmation};
+use git2::Repository;
+use log::{info, warn};
 
 /// Handle the 'config' command to configure global settings
 ///
 /// # Arguments
 ///
-/// * `default_base`         - Optional default base branch name.
-/// * `detection_strategy`   - Optional detection strategy for branch detection.
-/// * `add_relationship`     - Optional string in "parent:child" format to add a branch relationship.
-/// * `remove_relationship`  - Optional string in "parent:child" format to remove a branch relationship.
+/// * `args` - The parsed options of the 'config' subcommand. Every option that is set is
+///   applied and persisted; if none are set, the current configuration is printed.
 ///
 /// # Returns
 ///
@@ -28,28 +30,75 @@ use log::info;
 ///
 /// ```rust
 /// // Example usage:
-/// // handle_config(Some("main"), Some(BranchDetectionStrategy::Default), Some("main:feature"), None)?;
+/// // handle_config(&ConfigArgs { default_base: Some("main".to_string()), ..Default::default() })?;
 /// ```
-pub fn handle_config(
-    default_base: Option<&str>,
-    detection_strategy: Option<BranchDetectionStrategy>,
-    add_relationship: Option<&str>,
-    remove_relationship: Option<&str>,
-) -> Result<()> {
+pub fn handle_config(args: &ConfigArgs) -> Result<()> {
     let mut config = Config::load()?;
 
+    // Reset first, so any other options given apply on top of the defaults.
+    if args.reset {
+        if !prompt_confirmation("Replace the whole configuration with the defaults?")? {
+            if assume_no() {
+                info!("Not resetting the configuration (--assume-no)");
+                return Ok(());
+            }
+            return Err(GitFlowError::Aborted(
+                "Configuration reset cancelled".to_string(),
+            ));
+        }
+        config.reset()?;
+        info!("Configuration reset to defaults");
+    }
+
+    // Import next, so options given alongside apply on top of the imported settings.
+    if let Some(path) = &args.import {
+        config.import(path, args.replace)?;
+        info!("Imported configuration from {}", path.display());
+        if config.post_merge_command.is_some() || !config.create_hooks.is_empty() {
+            warn!(
+                "The configuration runs shell commands (post_merge_command, create_hooks); review them before running cascade or create"
+            );
+        }
+    }
+
+    if let Some(key) = &args.unset {
+        config.unset(key)?;
+        info!("Reset {} to its default", key);
+    }
+
     // Update configuration based on provided options
-    if let Some(base) = default_base {
-        config.set_default_base_branch(base.to_string())?;
+    if let Some(base) = &args.default_base {
+        let base = config.resolve_branch_alias(base);
+        config.set_default_base_branch(base.clone())?;
         info!("Default base branch set to: {}", base);
     }
 
-    if let Some(strategy) = detection_strategy {
+    if let Some(strategy) = args.detection_strategy {
         config.set_branch_detection_strategy(strategy.into())?;
         info!("Default branch detection strategy set to: {:?}", strategy);
     }
 
-    if let Some(relation) = add_relationship {
+    if let Some(threshold) = args.auto_strategy_threshold {
+        config.set_auto_strategy_threshold(threshold)?;
+        info!("Auto strategy threshold set to: {}", threshold);
+    }
+
+    if let Some(enabled) = args.history_first_parent {
+        config.set_history_first_parent(enabled)?;
+        info!("History first-parent mode set to: {}", enabled);
+    }
+
+    if let Some(enabled) = args.closest_parent {
+        config.set_closest_parent(enabled)?;
+        info!("Closest ancestor parent resolution set to: {}", enabled);
+    }
+
+    if let Some(enabled) = args.tree_cache {
+        config.set_tree_cache(enabled)?;
+        info!("Branch tree cache set to: {}", enabled);
+    }
+
+    if let Some(relation) = &args.add_relationship {
         // Parse parent:child format
         let parts: Vec<&str> = relation.split(':').collect();
         if parts.len() != 2 {
@@ -58,8 +107,9 @@ pub fn handle_config(
             ));
         }
 
-        let parent = parts[0].trim();
-        let child = parts[1].trim();
+        let parent = config.resolve_branch_alias(parts[0].trim());
+        let child = config.resolve_branch_alias(parts[1].trim());
+        let (parent, child) = (parent.as_str(), child.as_str());
 
         if parent.is_empty() || child.is_empty() {
             return Err(GitFlowError::Config(
@@ -67,14 +117,18 @@ pub fn handle_config(
             ));
         }
 
-        config.add_branch_relationship(parent.to_string(), child.to_string())?;
+        if args.shared {
+            git::shared::add_shared_relationship(&Repository::open(".")?, parent, child)?;
+        } else {
+            config.add_branch_relationship(parent.to_string(), child.to_string())?;
+        }
         info!(
             "Added branch relationship: {} is parent of {}",
             parent, child
         );
     }
 
-    if let Some(relation) = remove_relationship {
+    if let Some(relation) = &args.remove_relationship {
         // Parse parent:child format
         let parts: Vec<&str> = relation.split(':').collect();
         if parts.len() != 2 {
@@ -83,21 +137,302 @@ pub fn handle_config(
             ));
         }
 
-        let parent = parts[0].trim();
-        let child = parts[1].trim();
+        let parent = config.resolve_branch_alias(parts[0].trim());
+        let child = config.resolve_branch_alias(parts[1].trim());
+        let (parent, child) = (parent.as_str(), child.as_str());
 
-        config.remove_branch_relationship(parent, child)?;
+        if args.shared {
+            git::shared::remove_shared_relationship(&Repository::open(".")?, parent, child)?;
+        } else {
+            config.remove_branch_relationship(parent, child)?;
+        }
         info!(
             "Removed branch relationship: {} is parent of {}",
             parent, child
         );
     }
 
+    if let Some(rename) = &args.rename_branch {
+        // Parse old:new format
+        let parts: Vec<&str> = rename.split(':').collect();
+        if parts.len() != 2 {
+            return Err(GitFlowError::Config(
+                "Rename must be in format 'old:new'".to_string(),
+            ));
+        }
+
+        let old = parts[0].trim();
+        let new = parts[1].trim();
+
+        if old.is_empty() || new.is_empty() {
+            return Err(GitFlowError::Config(
+                "Old and new branch names cannot be empty".to_string(),
+            ));
+        }
+
+        let renamed = config.rename_branch(old, new)?;
+        info!(
+            "Renamed {} to {} in {} configuration entries",
+            old, new, renamed
+        );
+    }
+
+    if let Some(alias) = &args.add_branch_alias {
+        // Parse alias:branch format
+        let parts: Vec<&str> = alias.split(':').collect();
+        if parts.len() != 2 || parts[0].trim().is_empty() || parts[1].trim().is_empty() {
+            return Err(GitFlowError::Config(
+                "Alias must be in format 'alias:branch'".to_string(),
+            ));
+        }
+
+        let (alias, branch) = (parts[0].trim(), parts[1].trim());
+        config.add_branch_alias(alias.to_string(), branch.to_string())?;
+        info!("Added branch alias: {} -> {}", alias, branch);
+    }
+
+    if let Some(default) = &args.set_command_default {
+        // Parse command.flag=value format
+        let parsed = default
+            .split_once('=')
+            .and_then(|(key, value)| key.split_once('.').map(|(cmd, flag)| (cmd, flag, value)));
+        let Some((command, flag, value)) = parsed else {
+            return Err(GitFlowError::Config(
+                "Command default must be in format 'command.flag=value'".to_string(),
+            ));
+        };
+
+        let (command, flag, value) = (command.trim(), flag.trim(), value.trim());
+        config.set_command_default(command, flag, value.to_string())?;
+        info!("Default for {} --{} set to: {}", command, flag, value);
+    }
+
+    if let Some(enabled) = args.shared_relationships {
+        config.set_shared_relationships(enabled)?;
+        info!("Shared relationships set to: {}", enabled);
+    }
+
+    if let Some(pattern) = &args.add_ignore_branch {
+        config.add_ignore_branch(pattern.clone())?;
+        info!("Ignoring branches matching: {}", pattern);
+    }
+
+    if let Some(pattern) = &args.remove_ignore_branch {
+        config.remove_ignore_branch(pattern)?;
+        info!("No longer ignoring branches matching: {}", pattern);
+    }
+
+    if let Some(pattern) = &args.add_include_branch {
+        config.add_include_branch(pattern.clone())?;
+        info!("Only detecting branches matching: {}", pattern);
+    }
+
+    if let Some(pattern) = &args.remove_include_branch {
+        config.remove_include_branch(pattern)?;
+        info!(
+            "No longer restricting detection to branches matching: {}",
+            pattern
+        );
+    }
+
+    if let Some(enabled) = args.ascii_tree {
+        config.set_ascii_tree(enabled)?;
+        info!("ASCII tree glyphs set to: {}", enabled);
+    }
+
+    if let Some(sort) = args.show_sort {
+        config.set_show_sort(sort.into())?;
+        info!("Show sort order set to: {:?}", sort);
+    }
+
+    if let Some(pattern) = &args.ticket_pattern {
+        config.set_ticket_pattern(pattern.to_string())?;
+        info!("Ticket pattern set to: {}", pattern);
+    }
+
+    if let Some(mapping) = &args.add_ticket_base {
+        // Parse ticket:base format
+        let parts: Vec<&str> = mapping.split(':').collect();
+        if parts.len() != 2 {
+            return Err(GitFlowError::Config(
+                "Ticket base must be in format 'ticket:base'".to_string(),
+            ));
+        }
+
+        let ticket = parts[0].trim();
+        let base = parts[1].trim();
+
+        if ticket.is_empty() || base.is_empty() {
+            return Err(GitFlowError::Config(
+                "Ticket key and base branch names cannot be empty".to_string(),
+            ));
+        }
+
+        config.add_ticket_base(ticket.to_string(), base.to_string())?;
+        info!("Added ticket base: {} branches target {}", ticket, base);
+    }
+
+    if let Some(trailer) = &args.add_commit_trailer {
+        config.add_commit_trailer(trailer.trim().to_string())?;
+        info!("Added commit trailer: {}", trailer.trim());
+    }
+
+    if let Some(commit_type) = &args.add_commit_type {
+        config.add_commit_type(commit_type.trim().to_string())?;
+        info!("Accepting commit type: {}", commit_type.trim());
+    }
+
+    if let Some(commit_type) = &args.remove_commit_type {
+        config.remove_commit_type(commit_type.trim())?;
+        info!("No longer accepting commit type: {}", commit_type.trim());
+    }
+
+    if let Some(pattern) = &args.add_commit_exclude {
+        config.add_commit_exclude(pattern.clone())?;
+        info!("Excluding paths matching {} from commits", pattern);
+    }
+
+    if let Some(pattern) = &args.remove_commit_exclude {
+        config.remove_commit_exclude(pattern)?;
+        info!(
+            "No longer excluding paths matching {} from commits",
+            pattern
+        );
+    }
+
+    if let Some(threshold) = args.merge_rename_threshold {
+        config.set_merge_rename_threshold(threshold)?;
+        info!("Merge rename threshold set to: {}", threshold);
+    }
+
+    if let Some(enabled) = args.merge_include_commit_list {
+        config.set_merge_include_commit_list(enabled)?;
+        info!("Merge commit list set to: {}", enabled);
+    }
+
+    if let Some(enabled) = args.preserve_author_date {
+        config.set_preserve_author_date(enabled)?;
+        info!("Preserve author date set to: {}", enabled);
+    }
+
+    if let Some(enabled) = args.in_memory_merge {
+        config.set_in_memory_merge(enabled)?;
+        info!("In-memory merge set to: {}", enabled);
+    }
+
+    if let Some(enabled) = args.use_rerere {
+        config.set_use_rerere(enabled)?;
+        info!("Rerere set to: {}", enabled);
+    }
+
+    if let Some(option) = &args.set_merge_option {
+        // Parse flag=value format
+        let Some((key, value)) = option.split_once('=') else {
+            return Err(GitFlowError::Config(
+                "Merge option must be in format 'flag=value'".to_string(),
+            ));
+        };
+
+        let (key, value) = (key.trim(), value.trim());
+        config.set_merge_option(key, value)?;
+        info!("Merge option {} set to: {}", key, value);
+    }
+
+    if let Some(depth) = args.max_depth {
+        config.set_max_depth(depth)?;
+        info!("Maximum branch hierarchy depth set to: {}", depth);
+    }
+
+    if let Some(secs) = args.git_network_timeout_secs {
+        config.set_git_network_timeout_secs(secs)?;
+        info!("Network timeout set to: {}s", secs);
+    }
+
+    if let Some(retries) = args.git_network_retries {
+        config.set_git_network_retries(retries)?;
+        info!("Network retries set to: {}", retries);
+    }
+
+    if let Some(command) = &args.post_merge_command {
+        config.set_post_merge_command(command.clone())?;
+        info!("Post-merge command set to: {}", command);
+    }
+
+    if let Some(enabled) = args.post_merge_abort_on_failure {
+        config.set_post_merge_abort_on_failure(enabled)?;
+        info!("Abort on post-merge command failure set to: {}", enabled);
+    }
+
+    if let Some(command) = &args.add_create_hook {
+        config.add_create_hook(command.clone())?;
+        info!("Added create hook: {}", command);
+    }
+
+    if let Some(command) = &args.remove_create_hook {
+        config.remove_create_hook(command)?;
+        info!("Removed create hook: {}", command);
+    }
+
+    if let Some(enabled) = args.create_hooks_continue_on_failure {
+        config.set_create_hooks_continue_on_failure(enabled)?;
+        info!("Continue after a failed create hook set to: {}", enabled);
+    }
+
+    if let Some(path) = &args.export {
+        config.export(path)?;
+        info!("Exported configuration to {}", path.display());
+    }
+
+    if args.effective {
+        info!("Configuration loaded from {}", get_config_path()?.display());
+        println!("{}", serde_json::to_string_pretty(&config)?);
+        return Ok(());
+    }
+
     // If no options were provided, show current configuration
-    if default_base.is_none()
-        && detection_strategy.is_none()
-        && add_relationship.is_none()
-        && remove_relationship.is_none()
+    if !args.reset
+        && args.import.is_none()
+        && args.export.is_none()
+        && args.unset.is_none()
+        && args.default_base.is_none()
+        && args.detection_strategy.is_none()
+        && args.auto_strategy_threshold.is_none()
+        && args.history_first_parent.is_none()
+        && args.closest_parent.is_none()
+        && args.tree_cache.is_none()
+        && args.add_relationship.is_none()
+        && args.remove_relationship.is_none()
+        && args.rename_branch.is_none()
+        && args.shared_relationships.is_none()
+        && args.add_branch_alias.is_none()
+        && args.set_command_default.is_none()
+        && args.add_ignore_branch.is_none()
+        && args.remove_ignore_branch.is_none()
+        && args.add_include_branch.is_none()
+        && args.remove_include_branch.is_none()
+        && args.ascii_tree.is_none()
+        && args.show_sort.is_none()
+        && args.ticket_pattern.is_none()
+        && args.add_ticket_base.is_none()
+        && args.add_commit_trailer.is_none()
+        && args.add_commit_type.is_none()
+        && args.remove_commit_type.is_none()
+        && args.add_commit_exclude.is_none()
+        && args.remove_commit_exclude.is_none()
+        && args.merge_rename_threshold.is_none()
+        && args.merge_include_commit_list.is_none()
+        && args.preserve_author_date.is_none()
+        && args.in_memory_merge.is_none()
+        && args.use_rerere.is_none()
+        && args.set_merge_option.is_none()
+        && args.max_depth.is_none()
+        && args.git_network_timeout_secs.is_none()
+        && args.git_network_retries.is_none()
+        && args.post_merge_command.is_none()
+        && args.post_merge_abort_on_failure.is_none()
+        && args.add_create_hook.is_none()
+        && args.remove_create_hook.is_none()
+        && args.create_hooks_continue_on_failure.is_none()
     {
         info!("Current configuration:");
         info!("Default base branch: {}", config.default_base_branch);
@@ -105,6 +440,16 @@ pub fn handle_config(
             "Branch detection strategy: {:?}",
             config.branch_detection_strategy
         );
+        match config.auto_strategy_threshold {
+            Some(threshold) => info!("Auto strategy threshold: {} branches", threshold),
+            None => info!("Auto strategy threshold: disabled"),
+        }
+        info!("History first-parent mode: {}", config.history_first_parent);
+        info!(
+            "Closest ancestor parent resolution: {}",
+            config.closest_parent
+        );
+        info!("Branch tree cache: {}", config.tree_cache);
         info!("Manual branch relationships:");
 
         if config.branch_relationships.is_empty() {
@@ -117,6 +462,127 @@ pub fn handle_config(
             }
         }
 
+        info!("Shared relationships: {}", config.shared_relationships);
+        info!("Branch aliases:");
+        if config.branch_aliases.is_empty() {
+            info!("  None defined");
+        } else {
+            let mut aliases: Vec<_> = config.branch_aliases.iter().collect();
+            aliases.sort();
+            for (alias, branch) in aliases {
+                info!("  {} -> {}", alias, branch);
+            }
+        }
+        info!("Command defaults:");
+        if config.command_defaults.is_empty() {
+            info!("  None defined");
+        } else {
+            let mut defaults: Vec<(&String, &String, &String)> = config
+                .command_defaults
+                .iter()
+                .flat_map(|(cmd, flags)| flags.iter().map(move |(flag, value)| (cmd, flag, value)))
+                .collect();
+            defaults.sort();
+            for (command, flag, value) in defaults {
+                info!("  {}.{} = {}", command, flag, value);
+            }
+        }
+        info!("Ignored branch patterns:");
+
+        if config.ignore_branches.is_empty() {
+            info!("  None defined");
+        } else {
+            for pattern in &config.ignore_branches {
+                info!("  {}", pattern);
+            }
+        }
+
+        info!("Included branch patterns:");
+
+        if config.include_branches.is_empty() {
+            info!("  None defined (all branches)");
+        } else {
+            for pattern in &config.include_branches {
+                info!("  {}", pattern);
+            }
+        }
+
+        info!("ASCII tree glyphs: {}", config.ascii_tree);
+        info!("Show sort order: {:?}", config.show_sort);
+        info!(
+            "Ticket pattern: {}",
+            config.ticket_pattern.as_deref().unwrap_or("None")
+        );
+        info!("Ticket base branches:");
+
+        if config.ticket_base_map.is_empty() {
+            info!("  None defined");
+        } else {
+            for (ticket, base) in &config.ticket_base_map {
+                info!("  {} -> {}", ticket, base);
+            }
+        }
+
+        info!("Commit trailers:");
+
+        if config.commit_trailers.is_empty() {
+            info!("  None defined");
+        } else {
+            for trailer in &config.commit_trailers {
+                info!("  {}", trailer);
+            }
+        }
+
+        info!("Commit types: {}", config.commit_types().join(", "));
+
+        info!("Commit exclude patterns:");
+
+        if config.commit_exclude.is_empty() {
+            info!("  None defined");
+        } else {
+            for pattern in &config.commit_exclude {
+                info!("  {}", pattern);
+            }
+        }
+
+        match config.merge_rename_threshold {
+            Some(threshold) => info!("Merge rename threshold: {}", threshold),
+            None => info!("Merge rename threshold: default"),
+        }
+        info!("Merge commit list: {}", config.merge_include_commit_list);
+        info!("Preserve author date: {}", config.preserve_author_date);
+        info!("In-memory merge: {}", config.in_memory_merge);
+        info!("Rerere: {}", config.use_rerere);
+        info!(
+            "Merge options: {}",
+            serde_json::to_string(&config.merge_options)?
+        );
+        info!("Maximum branch hierarchy depth: {}", config.max_depth());
+        info!("Network timeout: {}s", config.git_network_timeout_secs());
+        info!("Network retries: {}", config.git_network_retries());
+        info!(
+            "Post-merge command: {}",
+            config.post_merge_command.as_deref().unwrap_or("None")
+        );
+        info!(
+            "Abort on post-merge command failure: {}",
+            config.post_merge_abort_on_failure
+        );
+
+        info!("Create hooks:");
+
+        if config.create_hooks.is_empty() {
+            info!("  None defined");
+        } else {
+            for command in &config.create_hooks {
+                info!("  {}", command);
+            }
+        }
+        info!(
+            "Continue after a failed create hook: {}",
+            config.create_hooks_continue_on_failure
+        );
+
         info!("Tracked PRs: {}", config.prs.len());
     }
 
@@ -1,24 +1,32 @@
 //! Module for the 'create' command.
 //!
-//! This module handles creating a new branch based on the current branch or
-//! a specified parent branch.
+//! This module handles creating a new branch based on the current branch, a specified
+//! parent branch, or a parent inferred from the nearest ancestor branch, and then running the
+//! configured create hooks.
 //!
 //! # Details
 //! This file is maintained with detailed documentation to aid future maintenance.
 //! Each function includes sections for arguments, returns, and examples.
 
-use crate::error::Result;
+use crate::configuration::Config;
+use crate::error::{GitFlowError, Result};
 use crate::git;
 use git2::Repository;
-use log::info;
+use log::{info, warn};
+use std::process::Command;
 
 /// Handle the 'create' command to create a new branch.
 ///
 /// # Arguments
 ///
-/// * `repo`   - A reference to the Git repository.
-/// * `name`   - The name of the new branch to create.
-/// * `parent` - An optional parent branch name to base the new branch upon.
+/// * `repo`        - A reference to the Git repository.
+/// * `name`        - The name of the new branch to create.
+/// * `parent`      - An optional parent branch name to base the new branch upon.
+/// * `parent_auto` - Infer the parent instead of using the current branch, preferring the base
+///   mapped to the ticket in the new branch's name.
+/// * `no_hooks`    - Skip the configured create hooks.
+/// * `dry_run`     - Check that the branch can be created and report what would be done,
+///   without creating it or running hooks.
 ///
 /// # Returns
 ///
@@ -29,12 +37,117 @@ use log::info;
 /// ```rust
 /// // Example usage:
 /// // let repo = Repository::open(".")?;
-/// // handle_new_branch(&repo, "feature-branch", Some("main"))?;
+/// // handle_new_branch(&repo, "feature-branch", Some("main"), false, false, false)?;
 /// ```
-pub fn handle_new_branch(repo: &Repository, name: &str, parent: Option<&str>) -> Result<()> {
+pub fn handle_new_branch(
+    repo: &Repository,
+    name: &str,
+    parent: Option<&str>,
+    parent_auto: bool,
+    no_hooks: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let config = Config::load()?;
+    let parent = if parent_auto {
+        // A ticket mapping for the new name routes the branch by convention.
+        if let Some(base) = config.get_ticket_base(name) {
+            info!("Using ticket base branch: {}", base);
+            base.clone()
+        } else {
+            let inferred = git::infer_parent(repo, &config)?;
+            info!("Inferred parent branch: {}", inferred);
+            inferred
+        }
+    } else {
+        match parent {
+            Some(name) => config.resolve_branch_alias(name),
+            None => git::get_current_branch(repo)?,
+        }
+    };
+
     // Create and checkout new branch by invoking the git helper.
-    git::create_new_branch(repo, name, parent)?;
+    git::create_new_branch(repo, name, Some(&parent), dry_run)?;
+    if dry_run {
+        if !no_hooks {
+            for command in &config.create_hooks {
+                info!("Would run create hook: {}", command);
+            }
+        }
+        return Ok(());
+    }
     // Log the successful creation of the branch.
     info!("Created and switched to branch: {}", name);
+
+    if no_hooks {
+        if !config.create_hooks.is_empty() {
+            info!("Skipping {} create hook(s)", config.create_hooks.len());
+        }
+        return Ok(());
+    }
+    run_create_hooks(repo, name, &parent, &config)
+}
+
+/// Run the configured create hooks, in order, for a newly created branch.
+///
+/// Each hook runs through `sh -c` in the repository's working directory, with
+/// `GITFLOW_BRANCH` and `GITFLOW_PARENT` set. The first failing hook stops the remaining ones
+/// unless `create_hooks_continue_on_failure` is set; the branch itself is kept either way.
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `branch` - The branch that was created.
+/// * `parent` - The branch it was created from.
+/// * `config` - Configuration providing the hooks.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok if every hook succeeded (or failures only warn), or an Aborted error
+///   naming the failed hook.
+///
+/// # Examples
+///
+/// ```rust
+/// // run_create_hooks(&repo, "feature", "main", &config)?;
+/// ```
+fn run_create_hooks(repo: &Repository, branch: &str, parent: &str, config: &Config) -> Result<()> {
+    let mut failed = 0;
+    for command in &config.create_hooks {
+        info!("Running create hook: {}", command);
+
+        let mut process = Command::new("sh");
+        process
+            .arg("-c")
+            .arg(command)
+            .env("GITFLOW_BRANCH", branch)
+            .env("GITFLOW_PARENT", parent);
+        if let Some(workdir) = repo.workdir() {
+            process.current_dir(workdir);
+        }
+
+        let failure = match process.status() {
+            Ok(status) if status.success() => continue,
+            Ok(status) => format!("exited with {}", status),
+            Err(e) => format!("could not be started: {}", e),
+        };
+        let message = format!("Create hook '{}' {}", command, failure);
+        if !config.create_hooks_continue_on_failure {
+            return Err(GitFlowError::Aborted(format!(
+                "{}; {} was created but the remaining hooks did not run",
+                message, branch
+            )));
+        }
+        warn!("{}", message);
+        failed += 1;
+    }
+
+    if failed > 0 {
+        warn!(
+            "{} of {} create hook(s) failed for {}",
+            failed,
+            config.create_hooks.len(),
+            branch
+        );
+    }
     Ok(())
 }
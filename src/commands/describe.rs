@@ -0,0 +1,43 @@
+//! Module for the 'describe' command.
+//!
+//! This module handles setting a branch's description, stored in Git's own
+//! `branch.<name>.description` setting and shown next to the branch by 'show'.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::configuration::Config;
+use crate::error::Result;
+use crate::git;
+use git2::Repository;
+use log::info;
+
+/// Handle the 'describe' command to set a branch description
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `branch` - An optional branch to describe (defaults to the current branch).
+/// * `text`   - The description text.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the branch does not exist.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_describe(&repo, None, "Adds the new login flow")?;
+/// ```
+pub fn handle_describe(repo: &Repository, branch: Option<&str>, text: &str) -> Result<()> {
+    let config = Config::load()?;
+    let branch_name = match branch {
+        Some(name) => config.resolve_branch_alias(name),
+        None => git::get_current_branch(repo)?,
+    };
+
+    git::set_branch_description(repo, &branch_name, text)?;
+    info!("Updated description of {}", branch_name);
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//! Module for the 'diff' command.
+//!
+//! This module handles showing the combined changes a branch introduces over its parent,
+//! either as a colored patch or as a diffstat summary.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::configuration::Config;
+use crate::error::Result;
+use crate::git;
+use colored::Colorize;
+use git2::{DiffFormat, DiffStatsFormat, Repository};
+use log::info;
+
+/// Handle the 'diff' command to show the changes of a branch over its parent
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `branch` - An optional branch to diff (defaults to the current branch).
+/// * `base`   - An optional base branch (defaults to the detected parent).
+/// * `stat`   - Print a diffstat summary instead of the patch.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if either branch cannot be found.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_diff(&repo, None, Some("main"), true)?;
+/// ```
+pub fn handle_diff(
+    repo: &Repository,
+    branch: Option<&str>,
+    base: Option<&str>,
+    stat: bool,
+) -> Result<()> {
+    let config = Config::load()?;
+    let branch_name = match branch {
+        Some(name) => config.resolve_branch_alias(name),
+        None => git::get_current_branch(repo)?,
+    };
+    let base_name = match base {
+        Some(name) => config.resolve_branch_alias(name),
+        None => git::get_parent_branch(repo, &branch_name, &config)?,
+    };
+    info!("Changes in {} over {}", branch_name, base_name);
+
+    let diff = git::diff::diff_against_base(repo, &branch_name, &base_name)?;
+
+    if stat {
+        let stats = diff.stats()?;
+        let buf = stats.to_buf(DiffStatsFormat::FULL, 80)?;
+        print!("{}", buf.as_str().unwrap_or(""));
+        return Ok(());
+    }
+
+    diff.print(DiffFormat::Patch, |_delta, _hunk, line| {
+        let content = String::from_utf8_lossy(line.content());
+        let content = content.trim_end_matches('\n');
+        match line.origin() {
+            '+' => println!("{}", format!("+{}", content).green()),
+            '-' => println!("{}", format!("-{}", content).red()),
+            ' ' => println!(" {}", content),
+            'F' => println!("{}", content.bold()),
+            'H' => println!("{}", content.cyan()),
+            _ => println!("{}", content),
+        }
+        true
+    })?;
+    Ok(())
+}
@@ -0,0 +1,48 @@
+//! Module for the 'doctor' command.
+//!
+//! This module handles checking the repository setup GitFlow relies on, repairing what can
+//! be repaired automatically and reporting anything that needs attention.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::configuration::Config;
+use crate::error::Result;
+use crate::git;
+use git2::Repository;
+use log::{info, warn};
+
+/// Handle the 'doctor' command to check and repair the repository setup
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the configuration cannot be loaded.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_doctor(&repo)?;
+/// ```
+pub fn handle_doctor(repo: &Repository) -> Result<()> {
+    let config = Config::load()?;
+
+    match git::remote::ensure_origin_head(repo) {
+        Ok(default_branch) => {
+            info!("Remote default branch: {}", default_branch);
+            if default_branch != config.default_base_branch {
+                warn!(
+                    "Configured default base branch '{}' differs from the remote default '{}'",
+                    config.default_base_branch, default_branch
+                );
+            }
+        }
+        Err(e) => warn!("Could not determine the remote default branch: {}", e),
+    }
+
+    Ok(())
+}
@@ -1,4 +1,21 @@
+pub mod abort;
 pub mod cascade;
+pub mod commit;
+pub mod compare;
 pub mod config;
 pub mod create;
+pub mod describe;
+pub mod diff;
+pub mod doctor;
+pub mod open;
+pub mod parent;
+pub mod preview;
+pub mod push;
+pub mod reparent;
+pub mod save;
 pub mod show;
+pub mod stale;
+pub mod status;
+pub mod switch;
+pub mod tag;
+pub mod validate;
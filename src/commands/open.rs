@@ -0,0 +1,85 @@
+//! Module for the 'open' command.
+//!
+//! This module handles opening a branch on the repository's hosting site in the browser,
+//! either the branch itself or the comparison against its parent, so its changes can be
+//! reviewed there before a pull request exists.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::configuration::Config;
+use crate::error::Result;
+use crate::git;
+use git2::Repository;
+use log::{info, warn};
+use std::process::Command;
+
+/// Handle the 'open' command to show a branch on the hosting site
+///
+/// The address is always printed, so it can still be used when no browser can be started.
+///
+/// # Arguments
+///
+/// * `repo`    - A reference to the Git repository.
+/// * `branch`  - An optional branch to open (defaults to the current branch).
+/// * `compare` - Open the comparison of the branch against its detected parent instead.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the remote URL cannot be turned into a web
+///   address.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_open(&repo, None, true)?;
+/// ```
+pub fn handle_open(repo: &Repository, branch: Option<&str>, compare: bool) -> Result<()> {
+    let config = Config::load()?;
+    let branch_name = match branch {
+        Some(name) => config.resolve_branch_alias(name),
+        None => git::get_current_branch(repo)?,
+    };
+
+    let base_url = git::remote::web_url(repo, &branch_name)?;
+    let url = if compare {
+        let parent = git::get_parent_branch(repo, &branch_name, &config)?;
+        format!("{}/compare/{}...{}", base_url, parent, branch_name)
+    } else {
+        format!("{}/tree/{}", base_url, branch_name)
+    };
+
+    println!("{}", url);
+    open_in_browser(&url);
+    Ok(())
+}
+
+/// Open an address in the default browser, warning if no browser could be started.
+///
+/// # Arguments
+///
+/// * `url` - The address to open.
+///
+/// # Examples
+///
+/// ```rust
+/// // open_in_browser("https://github.com/acme/widgets");
+/// ```
+fn open_in_browser(url: &str) {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+
+    match command.arg(url).status() {
+        Ok(status) if status.success() => info!("Opened {}", url),
+        Ok(status) => warn!("The browser could not be opened: exited with {}", status),
+        Err(e) => warn!("The browser could not be opened: {}", e),
+    }
+}
@@ -0,0 +1,87 @@
+//! Module for the 'parent' command.
+//!
+//! This module handles printing the detected parent of a single branch, on its own line and
+//! without decoration, so that scripts can use it directly.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::cli::BranchDetectionStrategy;
+use crate::configuration::Config;
+use crate::error::{GitFlowError, Result};
+use crate::git;
+use git2::{BranchType, Repository};
+use log::debug;
+
+/// Handle the 'parent' command to print the parent of a branch
+///
+/// Branches whose ticket key is mapped to a base branch print that base unless a strategy is
+/// given. Branches without a detected parent print the default base branch.
+///
+/// # Arguments
+///
+/// * `repo`     - A reference to the Git repository.
+/// * `branch`   - The branch whose parent is printed.
+/// * `strategy` - An optional branch detection strategy (defaults to the configured one).
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or a BranchNotFound error if the branch does not exist or
+///   is the default base branch, which has no parent.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_parent(&repo, "feature3", None)?;
+/// ```
+pub fn handle_parent(
+    repo: &Repository,
+    branch: &str,
+    strategy: Option<BranchDetectionStrategy>,
+) -> Result<()> {
+    let config = Config::load()?;
+    let branch_name = config.resolve_branch_alias(branch);
+    if repo.find_branch(&branch_name, BranchType::Local).is_err() {
+        return Err(GitFlowError::BranchNotFound(branch_name));
+    }
+
+    // A ticket mapping takes precedence over detection, as for the other commands.
+    if strategy.is_none()
+        && let Some(base) = config.get_ticket_base(&branch_name)
+    {
+        debug!("Using ticket base {} for {}", base, branch_name);
+        println!("{}", base);
+        return Ok(());
+    }
+
+    let strategy = match strategy {
+        Some(s) => s.into(),
+        None => git::select_strategy(repo, &config)?,
+    };
+    let branch_tree = git::get_branch_tree_cached(repo, strategy, &config, config.tree_cache)?;
+
+    let parent = branch_tree
+        .iter()
+        .find(|(_, children)| children.contains(&branch_name))
+        .map(|(parent, _)| parent.clone());
+    let parent = match parent {
+        Some(parent) => parent,
+        None if branch_name == config.default_base_branch => {
+            return Err(GitFlowError::BranchNotFound(format!(
+                "parent of {} (it is the default base branch)",
+                branch_name
+            )));
+        }
+        None => {
+            debug!(
+                "No parent detected for {}, using the default base",
+                branch_name
+            );
+            config.default_base_branch.clone()
+        }
+    };
+
+    println!("{}", parent);
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! Module for the 'preview' command.
+//!
+//! This module handles previewing a merge between two branches, listing the files that would
+//! conflict without modifying the working directory, the index or HEAD.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::configuration::Config;
+use crate::error::{GitFlowError, Result};
+use crate::git;
+use git2::{BranchType, Repository};
+use log::info;
+
+/// Handle the 'preview' command to list conflicting files for a merge
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+/// * `from` - The source branch name.
+/// * `to`   - The target branch name.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if either branch cannot be found.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_preview(&repo, "main", "feature")?;
+/// ```
+pub fn handle_preview(repo: &Repository, from: &str, to: &str) -> Result<()> {
+    let config = Config::load()?;
+    let from = &config.resolve_branch_alias(from);
+    let to = &config.resolve_branch_alias(to);
+
+    for name in [from, to] {
+        if repo.find_branch(name, BranchType::Local).is_err() {
+            return Err(GitFlowError::BranchNotFound(name.to_string()));
+        }
+    }
+
+    let conflicts = git::preview_conflicts(repo, from, to, &config)?;
+    if conflicts.is_empty() {
+        info!("Merging {} into {} would not conflict", from, to);
+        return Ok(());
+    }
+
+    info!("Merging {} into {} would conflict in:", from, to);
+    for path in &conflicts {
+        println!("  {}", path);
+    }
+    Ok(())
+}
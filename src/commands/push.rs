@@ -0,0 +1,201 @@
+//! Module for the 'push' command.
+//!
+//! This module handles publishing branches to their remotes without touching pull requests.
+//! It pushes either a single branch or every published branch in the stack, parents first.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::configuration::Config;
+use crate::error::{GitFlowError, Result};
+use crate::git;
+use git2::{BranchType, Repository};
+use log::{info, warn};
+
+/// Handle the 'push' command to publish branches
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `all`    - Push every local branch that has an upstream or matching remote branch.
+/// * `branch` - An optional branch to push (defaults to the current branch).
+/// * `force`  - Overwrite diverged remote branches.
+/// * `lease`  - Overwrite diverged remote branches, but only if they have not moved since the
+///   last fetch.
+/// * `rebase` - Rebase diverged branches onto their remote branch before pushing.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if any push fails.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_push(&repo, true, None, false, false, false)?;
+/// ```
+pub fn handle_push(
+    repo: &Repository,
+    all: bool,
+    branch: Option<&str>,
+    force: bool,
+    lease: bool,
+    rebase: bool,
+) -> Result<()> {
+    let config = Config::load()?;
+
+    if !all {
+        let branch_name = match branch {
+            Some(name) => config.resolve_branch_alias(name),
+            None => git::get_current_branch(repo)?,
+        };
+        warn_if_default_base(&config, &branch_name);
+        return push_checked(
+            repo,
+            &branch_name,
+            force,
+            lease,
+            rebase,
+            branch.is_some(),
+            &config,
+        );
+    }
+
+    let strategy = git::select_strategy(repo, &config)?;
+    let branch_tree = git::get_branch_tree(repo, strategy, &config)?;
+
+    // Push parents before children, then any branches outside the detected hierarchy.
+    let mut ordered = git::topological_order(&branch_tree);
+    for branch_result in repo.branches(Some(BranchType::Local))? {
+        let (branch, _) = branch_result?;
+        let name = branch.name()?.unwrap_or("").to_string();
+        if !name.is_empty() && !ordered.contains(&name) {
+            ordered.push(name);
+        }
+    }
+
+    let mut pushed = 0;
+    for name in &ordered {
+        if !git::remote::has_remote_branch(repo, name) {
+            info!("Skipping {}: not published on a remote", name);
+            continue;
+        }
+        warn_if_default_base(&config, name);
+        push_checked(repo, name, force, lease, rebase, false, &config)?;
+        pushed += 1;
+    }
+
+    info!("Pushed {} branch(es)", pushed);
+    Ok(())
+}
+
+/// Push a branch after checking whether the remote branch has commits the branch does not have.
+///
+/// The check is against the same-named branch on the push remote, since that is what the push
+/// updates, even when the branch tracks another branch such as `origin/main`. Unless a lease is
+/// used, that branch is fetched first so the check sees commits pushed by someone else since
+/// the last fetch. A branch that is only behind the remote branch has nothing to push and is
+/// skipped; the remote branch is only overwritten when it has really diverged
+/// and `force` or `lease` is set. The default base branch is never overwritten unless it was
+/// named explicitly.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `branch_name` - The branch to push.
+/// * `force`       - Overwrite the remote branch if it has diverged.
+/// * `lease`       - Overwrite the remote branch, provided it has not moved since the last fetch.
+/// * `rebase`      - Rebase the branch onto the remote branch first if it has diverged.
+/// * `explicit`    - Whether the user named the branch, which allows overwriting the default
+///   base branch.
+/// * `config`      - The loaded configuration.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the branch has diverged and none of `force`,
+///   `lease` or `rebase` is set, if the default base branch would be overwritten without being
+///   named, or if the lease does not hold.
+///
+/// # Examples
+///
+/// ```rust
+/// // push_checked(&repo, "feature", false, false, true, true, &config)?;
+/// ```
+pub fn push_checked(
+    repo: &Repository,
+    branch_name: &str,
+    force: bool,
+    lease: bool,
+    rebase: bool,
+    explicit: bool,
+    config: &Config,
+) -> Result<()> {
+    let remote_name = git::remote::get_upstream_remote(repo, branch_name)
+        .unwrap_or_else(|| git::remote::DEFAULT_REMOTE.to_string());
+
+    // Refresh the remote branch so commits someone else pushed are caught here rather than by a
+    // rejected push. A lease must compare against the last fetch, so it is left alone.
+    if !lease && git::remote::has_remote_branch(repo, branch_name) {
+        git::remote::fetch_branch(repo, &remote_name, branch_name)?;
+    }
+
+    let mut overwrite = false;
+    if let Some((ahead, behind)) = git::remote_divergence(repo, &remote_name, branch_name)?
+        && behind > 0
+    {
+        if ahead == 0 {
+            warn!(
+                "{} is {} commit(s) behind {}/{} and has nothing to push; skipping",
+                branch_name, behind, remote_name, branch_name
+            );
+            return Ok(());
+        }
+        warn!(
+            "{} has diverged from {}/{} ({} ahead, {} behind)",
+            branch_name, remote_name, branch_name, ahead, behind
+        );
+        if rebase {
+            let remote_branch = format!("{}/{}", remote_name, branch_name);
+            git::rebase::rebase_onto(repo, branch_name, &remote_branch, &remote_branch)?;
+        } else if force || lease {
+            if branch_name == config.default_base_branch && !explicit {
+                return Err(GitFlowError::Aborted(format!(
+                    "Refusing to overwrite the default base branch '{}' on the remote; name it with --branch to force-push it",
+                    branch_name
+                )));
+            }
+            overwrite = true;
+        } else {
+            return Err(GitFlowError::Aborted(format!(
+                "{} on the remote has commits that are not in the local branch. Re-run with --rebase to replay local commits on top of it, or --force-with-lease to overwrite it if nobody else pushed.",
+                branch_name
+            )));
+        }
+    }
+
+    if overwrite && lease {
+        git::remote::verify_lease(repo, &remote_name, branch_name)?;
+    }
+    git::push_branch(repo, branch_name, overwrite)
+}
+
+/// Warn when a push targets the default base branch, which is usually protected.
+///
+/// # Arguments
+///
+/// * `config`      - The loaded configuration.
+/// * `branch_name` - The branch about to be pushed.
+///
+/// # Examples
+///
+/// ```rust
+/// // warn_if_default_base(&config, "main");
+/// ```
+fn warn_if_default_base(config: &Config, branch_name: &str) {
+    if branch_name == config.default_base_branch {
+        warn!(
+            "Pushing directly to the default base branch '{}'; it may be protected on the remote",
+            branch_name
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Module for the 'reparent' command.
+//!
+//! This module handles moving a branch onto a new parent: it rebases the branch's own commits
+//! onto the new parent and updates the manual branch relationships to match.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::configuration::Config;
+use crate::error::{GitFlowError, Result};
+use crate::git;
+use git2::{BranchType, Repository};
+use log::info;
+
+/// Handle the 'reparent' command to move a branch onto a new parent
+///
+/// # Arguments
+///
+/// * `repo`       - A reference to the Git repository.
+/// * `branch`     - An optional branch to move (defaults to the current branch).
+/// * `new_parent` - The branch to use as the new parent.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the rebase fails.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_reparent(&repo, Some("feature"), "main")?;
+/// ```
+pub fn handle_reparent(repo: &Repository, branch: Option<&str>, new_parent: &str) -> Result<()> {
+    let mut config = Config::load()?;
+
+    let branch_name = match branch {
+        Some(name) => config.resolve_branch_alias(name),
+        None => git::get_current_branch(repo)?,
+    };
+    let new_parent = config.resolve_branch_alias(new_parent);
+    let new_parent = new_parent.as_str();
+
+    if branch_name == new_parent {
+        return Err(GitFlowError::Aborted(
+            "A branch cannot be its own parent".to_string(),
+        ));
+    }
+    for name in [branch_name.as_str(), new_parent] {
+        if repo.find_branch(name, BranchType::Local).is_err() {
+            return Err(GitFlowError::BranchNotFound(name.to_string()));
+        }
+    }
+
+    // Resolve the current parent so only the branch's own commits are moved.
+    let old_parent = git::get_parent_branch(repo, &branch_name, &config)?;
+    let upstream = if repo.find_branch(&old_parent, BranchType::Local).is_ok() {
+        old_parent.clone()
+    } else {
+        new_parent.to_string()
+    };
+    git::rebase::rebase_onto(repo, &branch_name, &upstream, new_parent)?;
+
+    // Replace any recorded parent of the branch with the new one.
+    let old_parents: Vec<String> = config
+        .branch_relationships
+        .iter()
+        .filter(|(_, children)| children.contains(&branch_name))
+        .map(|(parent, _)| parent.clone())
+        .collect();
+    for parent in old_parents {
+        config.remove_branch_relationship(&parent, &branch_name)?;
+    }
+    config.add_branch_relationship(new_parent.to_string(), branch_name.clone())?;
+
+    info!(
+        "Moved {} from {} onto {}",
+        branch_name, old_parent, new_parent
+    );
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! Module for the 'save' command.
+//!
+//! This module handles quick checkpoints: every change on the current branch is committed
+//! and the branch is pushed, in one step.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::commands::push::push_checked;
+use crate::configuration::Config;
+use crate::error::Result;
+use crate::git::commit::has_changes_to_commit;
+use crate::git::{self, StageMode};
+use git2::Repository;
+use log::info;
+
+/// Handle the 'save' command to commit all changes and push the current branch
+///
+/// Changes are staged like a plain 'commit', including untracked files, and the configured
+/// trailers and exclude patterns apply. When nothing is left to commit once excluded paths
+/// are left out, the commit is skipped and the branch is still pushed. The push refuses to
+/// overwrite commits on the remote, as 'push' does.
+///
+/// # Arguments
+///
+/// * `repo`    - A reference to the Git repository.
+/// * `message` - The commit message.
+/// * `push`    - Push the current branch after committing.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the commit or push fails.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_save(&repo, "wip", true)?;
+/// ```
+pub fn handle_save(repo: &Repository, message: &str, push: bool) -> Result<()> {
+    let config = Config::load()?;
+    let branch_name = git::get_current_branch(repo)?;
+
+    if !has_changes_to_commit(repo, &config.commit_exclude)? {
+        info!("Nothing to commit on {}", branch_name);
+    } else {
+        git::commit_changes(
+            repo,
+            message,
+            &StageMode::All,
+            &config.commit_trailers,
+            &config.commit_exclude,
+        )?;
+    }
+
+    if push {
+        push_checked(repo, &branch_name, false, false, false, true, &config)?;
+    }
+    Ok(())
+}
@@ -1,26 +1,32 @@
 //! Module for the 'show' command.
-//! 
+//!
 //! This module handles displaying the Git branch hierarchy along with pull request (PR)
 //! information and commit messages. It loads configuration, determines the branch detection
-//! strategy, and prints the branch structure accordingly.
+//! strategy, and prints the branch structure accordingly, or writes it to a file.
 //!
 //! # Details
 //! Detailed documentation is provided for easier maintenance and clarity.
 
-use crate::cli::BranchDetectionStrategy;
+use crate::cli::ShowArgs;
 use crate::configuration::Config;
 use crate::error::Result;
 use crate::git;
-use crate::utils::print_branch_hierarchy;
+use crate::utils::{BranchDetails, render_branch_hierarchy, select_glyphs};
 use git2::{BranchType, Repository};
-use log::info;
-use std::collections::HashMap;
+use log::{info, warn};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+/// Number of hex digits shown for a branch tip with `--hashes`.
+const SHORT_HASH_LEN: usize = 7;
 
 /// Handle the 'show' command to display branch structure with PR information
 ///
 /// # Arguments
 /// * `repo` - A reference to the Git repository.
-/// * `strategy_opt` - An optional branch detection strategy from the CLI.
+/// * `args` - The parsed options of the 'show' subcommand. `output` is written without colors,
+///   and `only` replaces the configured include patterns for this run.
 ///
 /// # Returns
 /// * `Result<()>` - Returns an empty Ok result on success or an error on failure.
@@ -29,81 +35,203 @@ use std::collections::HashMap;
 /// ```rust
 /// // Example usage:
 /// // let repo = Repository::open(".")?;
-/// // handle_show(&repo, Some(BranchDetectionStrategy::Default))?;
+/// // handle_show(&repo, &ShowArgs { parents: true, ..Default::default() })?;
 /// ```
-pub fn handle_show(repo: &Repository, strategy_opt: Option<BranchDetectionStrategy>) -> Result<()> {
+pub fn handle_show(repo: &Repository, args: &ShowArgs) -> Result<()> {
     // Load configuration for branch detection strategy.
-    let config = Config::load()?;
+    let mut config = Config::load()?;
+    let output = args.output.as_deref();
+
+    // Temporarily lift the ignore patterns; the configuration is not saved.
+    if args.include_ignored {
+        config.ignore_branches.clear();
+    }
+    config.override_include_branches(&args.only)?;
+
+    for warning in config.validate_against_repo(repo) {
+        warn!("{}", warning);
+    }
 
     // Determine which branch detection strategy to use.
     // Use the provided strategy if available; otherwise, fallback to the configuration setting.
-    let strategy = match strategy_opt {
+    let strategy = match args.strategy {
         Some(s) => s.into(),
-        None => config.branch_detection_strategy,
+        None => git::select_strategy(repo, &config)?,
     };
 
     // Log the selected branch detection strategy for debugging purposes.
     info!("Using branch detection strategy: {:?}", strategy);
 
     // Retrieve the branch hierarchy using the determined strategy.
-    let branch_tree = git::get_branch_tree(repo, strategy, &config)?;
+    let use_cache = config.tree_cache && !args.no_cache;
+    let mut branch_tree = git::get_branch_tree_cached(repo, strategy, &config, use_cache)?;
 
     // Retrieve the current branch to enable highlighting in the output.
     let current_branch = git::get_current_branch(repo)?;
 
+    // Files get plain text; color codes are only meant for the terminal.
+    if output.is_some() {
+        colored::control::set_override(false);
+    }
+
+    if args.parents {
+        return write_output(&render_parent_list(&branch_tree), output);
+    }
+
     // If no branch hierarchy is detected, list all local branches.
     if branch_tree.is_empty() {
         info!("No branch hierarchy detected.");
 
-        // Iterate over local branches to print their status.
+        // Iterate over local branches to list their status.
+        let mut rendered = String::new();
         let branches = repo.branches(Some(BranchType::Local))?;
         for branch_result in branches {
             let (branch, _) = branch_result?;
             let name = branch.name()?.unwrap_or("").to_string();
+            if !git::is_detected_branch(&config.include_branches, &config.ignore_branches, &name) {
+                continue;
+            }
 
-            println!(
-                "{} is {}",
+            rendered.push_str(&format!(
+                "{} is {}\n",
                 name,
                 if name == current_branch {
                     "current"
                 } else {
                     "not current"
                 }
-            );
+            ));
         }
-        return Ok(());
+        return write_output(&rendered, output);
     }
 
     // Identify root branches (branches without parent branches).
-    let root_branches = git::find_root_branches(&branch_tree);
+    let mut root_branches = git::find_root_branches(&branch_tree);
+
+    // List roots and the children of every branch in the configured order.
+    let sort = args.sort.map_or(config.show_sort, Into::into);
+    git::sort_branches(repo, &mut root_branches, None, sort)?;
+    git::sort_branch_tree(repo, &mut branch_tree, sort)?;
+
+    let mut details = BranchDetails::default();
 
     // Collect pull request (PR) information for each branch from the configuration.
-    let mut pr_info = HashMap::new();
     for (branch, info) in &config.prs {
-        pr_info.insert(branch.clone(), (info.number, info.url.clone()));
+        details
+            .pr_info
+            .insert(branch.clone(), (info.number, info.url.clone()));
     }
 
     // Collect the first line of the commit message for each branch.
-    let mut commit_messages = HashMap::new();
     for branch_name in branch_tree.keys() {
-        if let Ok(commit) = git::get_branch_commit(repo, branch_name) {
-            if let Some(message) = commit.message() {
-                commit_messages.insert(
-                    branch_name.clone(),
-                    message.lines().next().unwrap_or("").to_string(),
-                );
+        if let Ok(commit) = git::get_branch_commit(repo, branch_name)
+            && let Some(message) = commit.message()
+        {
+            details.commit_messages.insert(
+                branch_name.clone(),
+                message.lines().next().unwrap_or("").to_string(),
+            );
+        }
+    }
+
+    // Collect the abbreviated tip hash of every branch in the hierarchy when requested.
+    if args.hashes {
+        for branch_name in branch_tree.keys().chain(branch_tree.values().flatten()) {
+            if let Ok(commit) = git::get_branch_commit(repo, branch_name) {
+                let mut hash = commit.id().to_string();
+                hash.truncate(SHORT_HASH_LEN);
+                details.commit_hashes.insert(branch_name.clone(), hash);
             }
         }
     }
 
-    // Print the complete branch hierarchy along with PR and commit message details.
-    print_branch_hierarchy(
+    // Collect branch descriptions for every branch in the hierarchy.
+    for branch_name in branch_tree.keys().chain(branch_tree.values().flatten()) {
+        if let Some(description) = git::get_branch_description(repo, branch_name) {
+            details
+                .descriptions
+                .insert(branch_name.clone(), description);
+        }
+    }
+
+    // Render the complete branch hierarchy along with PR, commit message and description details.
+    let glyphs = select_glyphs(args.ascii || config.ascii_tree);
+    let rendered = render_branch_hierarchy(
         &branch_tree,
         &root_branches,
         &current_branch,
-        &pr_info,
-        &commit_messages,
+        &details,
+        &glyphs,
+        config.max_depth(),
     );
 
+    write_output(&rendered, output)
+}
+
+/// Render one "branch: parent" line per branch of the hierarchy, sorted by branch name
+///
+/// Root branches are listed with "(root)" as their parent, and a branch with several parents
+/// lists them separated by commas.
+///
+/// # Arguments
+/// * `branch_tree` - Mapping from parent branches to child branches.
+///
+/// # Returns
+/// * `String` - The rendered list, one line per branch.
+///
+/// # Examples
+/// ```rust
+/// // Example usage:
+/// // print!("{}", render_parent_list(&branch_tree));
+/// ```
+fn render_parent_list(branch_tree: &HashMap<String, Vec<String>>) -> String {
+    // Invert the parent -> children map.
+    let mut parents: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (parent, children) in branch_tree {
+        parents.entry(parent).or_default();
+        for child in children {
+            parents.entry(child).or_default().push(parent);
+        }
+    }
+
+    let mut rendered = String::new();
+    for (branch, mut branch_parents) in parents {
+        if branch_parents.is_empty() {
+            rendered.push_str(&format!("{}: (root)\n", branch));
+        } else {
+            branch_parents.sort();
+            rendered.push_str(&format!("{}: {}\n", branch, branch_parents.join(", ")));
+        }
+    }
+    rendered
+}
+
+/// Print the rendered branch structure, or write it to a file
+///
+/// # Arguments
+/// * `rendered` - The rendered branch structure.
+/// * `output` - The file to write to, creating missing parent directories; stdout if None.
+///
+/// # Returns
+/// * `Result<()>` - Returns an empty Ok result on success or an error if the file cannot be written.
+///
+/// # Examples
+/// ```rust
+/// // Example usage:
+/// // write_output(&rendered, Some(Path::new("docs/branches.txt")))?;
+/// ```
+fn write_output(rendered: &str, output: Option<&Path>) -> Result<()> {
+    let Some(path) = output else {
+        print!("{}", rendered);
+        return Ok(());
+    };
+
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, rendered)?;
+    println!("Branch structure written to {}", path.display());
     Ok(())
 }
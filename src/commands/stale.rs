@@ -0,0 +1,130 @@
+//! Module for the 'stale' command.
+//!
+//! This module handles finding purely local branches that look abandoned: no upstream, no
+//! tracked pull request and no commits for a number of days. The listed branches can be
+//! deleted in bulk after confirmation. The default base branch and branches that other
+//! branches build on are never listed, and branches with commits their parent does not have
+//! are kept.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::configuration::Config;
+use crate::error::{GitFlowError, Result};
+use crate::git;
+use crate::utils::{assume_no, prompt_confirmation};
+use git2::{BranchType, Repository};
+use log::{info, warn};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Handle the 'stale' command to list and optionally delete stale local branches
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+/// * `days` - Minimum age in days of a branch tip for the branch to count as stale.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the branches cannot be read or deleted.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_stale(&repo, 30)?;
+/// ```
+pub fn handle_stale(repo: &Repository, days: u64) -> Result<()> {
+    let config = Config::load()?;
+    let git_config = repo.config()?;
+    let current_branch = git::get_current_branch(repo).ok();
+
+    // Branches with children in the hierarchy are still being built on.
+    let strategy = git::select_strategy(repo, &config)?;
+    let branch_tree = git::get_branch_tree(repo, strategy, &config)?;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| GitFlowError::Unknown(format!("System clock is before 1970: {}", e)))?
+        .as_secs() as i64;
+    // An age too large for the clock means no branch is old enough.
+    let max_age = i64::try_from(days)
+        .ok()
+        .and_then(|days| days.checked_mul(SECONDS_PER_DAY))
+        .unwrap_or(i64::MAX);
+    let cutoff = now.saturating_sub(max_age);
+
+    let mut stale = Vec::new();
+    for branch_result in repo.branches(Some(BranchType::Local))? {
+        let (branch, _) = branch_result?;
+        let Some(name) = branch.name()? else {
+            continue;
+        };
+
+        // The current branch cannot be deleted, and the default base, branches with children
+        // and branches with an upstream or PR are in use.
+        if current_branch.as_deref() == Some(name)
+            || name == config.default_base_branch
+            || branch_tree
+                .get(name)
+                .is_some_and(|children| !children.is_empty())
+            || git_config
+                .get_string(&format!("branch.{}.merge", name))
+                .is_ok()
+            || config.get_pr(name).is_some()
+        {
+            continue;
+        }
+
+        let tip_time = branch.get().peel_to_commit()?.time().seconds();
+        if tip_time > cutoff {
+            continue;
+        }
+
+        // Deleting a branch whose work never reached its parent would lose commits.
+        let parent = git::get_parent_branch(repo, name, &config)?;
+        if !git::is_up_to_date(repo, name, &parent).unwrap_or(false) {
+            warn!(
+                "Keeping {}: it has commits that are not merged into {}",
+                name, parent
+            );
+            continue;
+        }
+        stale.push((name.to_string(), (now - tip_time) / SECONDS_PER_DAY));
+    }
+
+    if stale.is_empty() {
+        println!(
+            "No merged local branches without an upstream or PR older than {} days",
+            days
+        );
+        return Ok(());
+    }
+
+    stale.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    println!(
+        "Stale branches (merged, no upstream, no PR, older than {} days):",
+        days
+    );
+    for (name, age) in &stale {
+        println!("  {} ({} days old)", name, age);
+    }
+
+    if !prompt_confirmation(&format!("Delete these {} branch(es)?", stale.len()))? {
+        if assume_no() {
+            info!("Keeping the stale branches (--assume-no)");
+            return Ok(());
+        }
+        return Err(GitFlowError::Aborted(
+            "Stale branch deletion cancelled".to_string(),
+        ));
+    }
+
+    for (name, _) in &stale {
+        repo.find_branch(name, BranchType::Local)?.delete()?;
+        info!("Deleted {}", name);
+    }
+    Ok(())
+}
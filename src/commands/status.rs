@@ -0,0 +1,91 @@
+//! Module for the 'status' command.
+//!
+//! This module handles listing the changed files in the working tree in a short, two-column
+//! format, optionally limited to some paths so one part of a monorepo can be looked at alone.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::error::Result;
+use crate::git;
+use git2::{Repository, Status};
+use log::info;
+
+/// Handle the 'status' command to list changed files
+///
+/// # Arguments
+///
+/// * `repo`  - A reference to the Git repository.
+/// * `paths` - Paths to limit the output to; the whole working tree if empty.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the status cannot be read.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_status(&repo, &["services/api".to_string()])?;
+/// ```
+pub fn handle_status(repo: &Repository, paths: &[String]) -> Result<()> {
+    let pathspec = (!paths.is_empty()).then_some(paths);
+    let entries = git::status::get_repo_status(repo, true, pathspec)?;
+
+    if entries.is_empty() {
+        match pathspec {
+            Some(paths) => info!("No changes under {}", paths.join(", ")),
+            None => info!("Working tree clean"),
+        }
+        return Ok(());
+    }
+
+    for entry in &entries {
+        println!("{} {}", status_code(entry.status), entry.path);
+    }
+    Ok(())
+}
+
+/// Format a file status as the two-letter code used by `git status --short`.
+///
+/// # Arguments
+///
+/// * `status` - The status of the file.
+///
+/// # Returns
+///
+/// * `String` - The index state followed by the working tree state.
+fn status_code(status: Status) -> String {
+    if status.is_conflicted() {
+        return "UU".to_string();
+    }
+    if status.is_wt_new() {
+        return "??".to_string();
+    }
+
+    let index = if status.is_index_new() {
+        'A'
+    } else if status.is_index_modified() {
+        'M'
+    } else if status.is_index_deleted() {
+        'D'
+    } else if status.is_index_renamed() {
+        'R'
+    } else if status.is_index_typechange() {
+        'T'
+    } else {
+        ' '
+    };
+    let worktree = if status.is_wt_modified() {
+        'M'
+    } else if status.is_wt_deleted() {
+        'D'
+    } else if status.is_wt_renamed() {
+        'R'
+    } else if status.is_wt_typechange() {
+        'T'
+    } else {
+        ' '
+    };
+    format!("{}{}", index, worktree)
+}
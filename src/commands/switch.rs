@@ -0,0 +1,134 @@
+//! Module for the 'switch' command.
+//!
+//! This module handles switching branches from a numbered menu of local branches, optionally
+//! narrowed down by a fuzzy query, stashing uncommitted changes across the switch on request.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::configuration::Config;
+use crate::error::{GitFlowError, Result};
+use crate::git;
+use crate::utils::{assume_no, prompt_confirmation, prompt_selection};
+use git2::{BranchType, Repository, StashFlags};
+use log::{info, warn};
+
+/// Handle the 'switch' command to check out a branch picked from a menu
+///
+/// # Arguments
+///
+/// * `repo`  - A mutable reference to the Git repository (needed to stash changes).
+/// * `query` - An optional fuzzy query narrowing down the listed branches.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if no branch matches or the switch is cancelled.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_switch(&mut repo, Some("login"))?;
+/// ```
+pub fn handle_switch(repo: &mut Repository, query: Option<&str>) -> Result<()> {
+    let config = Config::load()?;
+    let current = git::get_current_branch(repo).ok();
+
+    let mut branches = Vec::new();
+    for branch_result in repo.branches(Some(BranchType::Local))? {
+        let (branch, _) = branch_result?;
+        let name = branch.name()?.unwrap_or("").to_string();
+        if name.is_empty()
+            || current.as_deref() == Some(name.as_str())
+            || git::is_ignored_branch(&config.ignore_branches, &name)
+            || query.is_some_and(|query| !fuzzy_match(query, &name))
+        {
+            continue;
+        }
+        branches.push(name);
+    }
+    branches.sort();
+
+    let target = match branches.len() {
+        0 => {
+            return Err(GitFlowError::BranchNotFound(
+                query.unwrap_or("no other branches").to_string(),
+            ));
+        }
+        1 => branches.remove(0),
+        _ => {
+            for (index, name) in branches.iter().enumerate() {
+                println!("{:>3}) {}", index + 1, annotate(repo, &config, name));
+            }
+            match prompt_selection("Switch to", branches.len())? {
+                Some(index) => branches.swap_remove(index),
+                None if assume_no() => return Ok(()),
+                None => return Err(GitFlowError::Aborted("No branch selected".to_string())),
+            }
+        }
+    };
+
+    // Carry uncommitted changes over to the new branch only when asked to.
+    let dirty = !git::status::get_repo_status(repo, false, None)?.is_empty();
+    if dirty {
+        let message = "There are uncommitted changes. Stash them and re-apply after switching?";
+        if !prompt_confirmation(message)? {
+            return Err(GitFlowError::Aborted(
+                "There are uncommitted changes. Please commit or stash them first.".to_string(),
+            ));
+        }
+        let sig = repo.signature()?;
+        repo.stash_save(&sig, "gitflow switch autostash", Some(StashFlags::DEFAULT))?;
+    }
+
+    git::checkout_branch(repo, &target)?;
+    info!("Switched to branch: {}", target);
+
+    if dirty && let Err(e) = repo.stash_pop(0, None) {
+        warn!(
+            "Could not re-apply stashed changes ({}); they are kept in the stash",
+            e.message()
+        );
+    }
+    Ok(())
+}
+
+/// Check whether every character of the query appears in the branch name, in order.
+///
+/// # Arguments
+///
+/// * `query` - The fuzzy query, matched case-insensitively.
+/// * `name`  - The branch name.
+///
+/// # Returns
+///
+/// * `bool` - True if the name matches the query.
+fn fuzzy_match(query: &str, name: &str) -> bool {
+    let name = name.to_lowercase();
+    let mut chars = name.chars();
+    query.to_lowercase().chars().all(|q| chars.any(|c| c == q))
+}
+
+/// Format a menu entry with the branch's PR number and latest commit summary.
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `config` - Configuration holding the tracked PRs.
+/// * `name`   - The branch name.
+///
+/// # Returns
+///
+/// * `String` - The annotated menu entry.
+fn annotate(repo: &Repository, config: &Config, name: &str) -> String {
+    let mut entry = name.to_string();
+    if let Some(pr) = config.prs.get(name) {
+        entry.push_str(&format!(" [PR #{}]", pr.number));
+    }
+    if let Ok(commit) = git::get_branch_commit(repo, name)
+        && let Some(summary) = commit.summary()
+    {
+        entry.push_str(&format!(" - {}", summary));
+    }
+    entry
+}
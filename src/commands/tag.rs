@@ -0,0 +1,56 @@
+//! Module for the 'tag' command.
+//!
+//! This module handles tagging the tip of a branch, for example a merged release branch,
+//! and optionally publishing the tag.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::configuration::Config;
+use crate::error::Result;
+use crate::git;
+use git2::Repository;
+
+/// Handle the 'tag' command to tag a branch tip
+///
+/// # Arguments
+///
+/// * `repo`    - A reference to the Git repository.
+/// * `name`    - The tag name.
+/// * `message` - Optional tag message; creates an annotated tag when present.
+/// * `branch`  - An optional branch to tag (defaults to the current branch).
+/// * `push`    - Push the tag to the remote after creating it.
+/// * `force`   - Overwrite an existing tag with the same name.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the tag exists or the push fails.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_tag(&repo, "v1.0.0", Some("Release 1.0"), None, true, false)?;
+/// ```
+pub fn handle_tag(
+    repo: &Repository,
+    name: &str,
+    message: Option<&str>,
+    branch: Option<&str>,
+    push: bool,
+    force: bool,
+) -> Result<()> {
+    let config = Config::load()?;
+    let branch_name = match branch {
+        Some(name) => config.resolve_branch_alias(name),
+        None => git::get_current_branch(repo)?,
+    };
+
+    let signer = repo.signature()?;
+    git::tag::create_tag(repo, name, &branch_name, message, &signer, force)?;
+
+    if push {
+        git::tag::push_tag(repo, name, force)?;
+    }
+    Ok(())
+}
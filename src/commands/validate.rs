@@ -0,0 +1,97 @@
+//! Module for the 'validate' command.
+//!
+//! This module handles checking the branch configuration for problems that would break a
+//! cascade: cycles in the branch hierarchy and branches named in the configuration that do
+//! not exist. It is meant as a CI gate, so problems can also be printed as JSON.
+//!
+//! # Details
+//! Detailed documentation is provided for easier maintenance and clarity.
+
+use crate::cli::OutputFormat;
+use crate::configuration::Config;
+use crate::error::{GitFlowError, Result};
+use crate::git;
+use git2::Repository;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// A problem found by 'validate'
+#[derive(Debug, Serialize)]
+struct Problem {
+    /// The kind of problem, `cycle` or `missing_branch`.
+    kind: &'static str,
+    /// A description of the problem.
+    message: String,
+}
+
+/// Handle the 'validate' command to check the branch configuration
+///
+/// Cycles are looked for both in the manual relationships and in the tree detected with the
+/// configured strategy; the configured branches must exist locally or on a remote.
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `format` - Print the problems as text or as JSON.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok if no problems were found, or an error if there are problems or the
+///   branch tree cannot be built.
+///
+/// # Examples
+///
+/// ```rust
+/// // Example usage:
+/// // handle_validate(&repo, OutputFormat::Json)?;
+/// ```
+pub fn handle_validate(repo: &Repository, format: OutputFormat) -> Result<()> {
+    let config = Config::load()?;
+    let strategy = git::select_strategy(repo, &config)?;
+    let branch_tree = git::get_branch_tree(repo, strategy, &config)?;
+
+    let mut cycles = HashSet::new();
+    let mut problems = Vec::new();
+    for cycle in git::find_cycles(&config.branch_relationships)
+        .into_iter()
+        .chain(git::find_cycles(&branch_tree))
+    {
+        if cycles.insert(cycle.clone()) {
+            problems.push(Problem {
+                kind: "cycle",
+                message: format!(
+                    "Branch hierarchy has a cycle: {} -> {}",
+                    cycle.join(" -> "),
+                    cycle[0]
+                ),
+            });
+        }
+    }
+    for warning in config.validate_against_repo(repo) {
+        problems.push(Problem {
+            kind: "missing_branch",
+            message: warning,
+        });
+    }
+
+    match format {
+        OutputFormat::Text if problems.is_empty() => println!("No problems found"),
+        OutputFormat::Text => {
+            for problem in &problems {
+                println!("{}: {}", problem.kind, problem.message);
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({ "problems": problems }))?
+        ),
+    }
+
+    if !problems.is_empty() {
+        return Err(GitFlowError::Config(format!(
+            "{} problem(s) found in the branch configuration",
+            problems.len()
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//! Module for per-command flag defaults.
+//!
+//! This module lists the command flags that can be given a default in the configuration and
+//! parses the configured values, so that the configuration can validate them without
+//! depending on the command line definitions.
+//!
+//! # Details
+//! Detailed documentation is provided for clear maintenance and future updates.
+
+use crate::error::{GitFlowError, Result};
+use crate::git::branch::BranchRelationStrategy;
+
+/// Flags that can be given a per-command default in the configuration, as (command, flag)
+pub const COMMAND_DEFAULT_KEYS: &[(&str, &str)] = &[
+    ("cascade", "yes"),
+    ("cascade", "no-cache"),
+    ("cascade", "fetch-first"),
+    ("cascade", "finalize"),
+    ("cascade", "strategy"),
+    ("show", "no-cache"),
+    ("show", "include-ignored"),
+    ("show", "ascii"),
+    ("show", "hashes"),
+    ("show", "strategy"),
+    ("commit", "tracked-only"),
+    ("commit", "signoff"),
+    ("push", "rebase"),
+    ("tag", "push"),
+];
+
+/// Parse the value of a boolean command default.
+///
+/// # Arguments
+///
+/// * `command` - The command the default belongs to.
+/// * `flag`    - The flag name.
+/// * `value`   - The configured value, `true` or `false`.
+///
+/// # Returns
+///
+/// * `Result<bool>` - The parsed value, or an error if it is not a boolean.
+pub fn parse_default_flag(command: &str, flag: &str, value: &str) -> Result<bool> {
+    value.parse().map_err(|_| {
+        GitFlowError::Config(format!(
+            "Invalid value '{}' for {}.{}: expected true or false",
+            value, command, flag
+        ))
+    })
+}
+
+/// Parse the value of a `strategy` command default.
+///
+/// # Arguments
+///
+/// * `command` - The command the default belongs to.
+/// * `value`   - The configured strategy name, as accepted by `--strategy`.
+///
+/// # Returns
+///
+/// * `Result<BranchRelationStrategy>` - The parsed strategy, or an error if it is unknown.
+pub fn parse_default_strategy(command: &str, value: &str) -> Result<BranchRelationStrategy> {
+    match value.to_ascii_lowercase().as_str() {
+        "history" => Ok(BranchRelationStrategy::CommitHistory),
+        "time" => Ok(BranchRelationStrategy::CreationTime),
+        "default" => Ok(BranchRelationStrategy::DefaultRoot),
+        "manual" => Ok(BranchRelationStrategy::Manual),
+        _ => Err(GitFlowError::Config(format!(
+            "Invalid value '{}' for {}.strategy: expected history, time, default or manual",
+            value, command
+        ))),
+    }
+}
@@ -1,3 +1,4 @@
+pub mod defaults;
 pub mod settings;
 
 pub use settings::Config;
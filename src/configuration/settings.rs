@@ -7,12 +7,38 @@
 //! # Details
 //! Detailed documentation is provided for clear maintenance and future updates.
 
+use crate::configuration::defaults::{
+    COMMAND_DEFAULT_KEYS, parse_default_flag, parse_default_strategy,
+};
 use crate::error::{GitFlowError, Result};
-use crate::git::branch::BranchRelationStrategy;
+use crate::git::branch::{BranchRelationStrategy, BranchSortOrder};
+use git2::{BranchType, Repository};
+use glob::Pattern;
+use log::warn;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Directory name of gitflow's files when `GITFLOW_APP_NAME` is not set.
+pub const DEFAULT_APP_NAME: &str = "gitflow";
+
+/// Branch hierarchy depth followed when `max_depth` is not configured.
+pub const DEFAULT_MAX_DEPTH: usize = 500;
+
+/// Seconds to wait for a remote when `git_network_timeout_secs` is not configured.
+pub const DEFAULT_NETWORK_TIMEOUT_SECS: u64 = 30;
+
+/// Retries after transient network errors when `git_network_retries` is not configured.
+pub const DEFAULT_NETWORK_RETRIES: u32 = 2;
+
+/// Conventional commit types accepted by `commit --type` when `commit_types` is not configured.
+pub const DEFAULT_COMMIT_TYPES: &[&str] = &[
+    "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+];
 
 /// PR information stored in configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,22 +49,234 @@ pub struct PrInfo {
     pub created_at: String,
 }
 
+/// Standard merge flags passed to git2; unset flags keep git2's defaults.
+///
+/// `ignore_whitespace*`, `find_renames`, `minimal` and `patience` all change which hunks or
+/// files are reported as conflicting, not just how the merged result is built.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MergeSettings {
+    /// Detect renamed files, so edits to a renamed file merge instead of conflicting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub find_renames: Option<bool>,
+
+    /// Ignore all whitespace when comparing lines.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_whitespace: Option<bool>,
+
+    /// Ignore changes in the amount of whitespace when comparing lines.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_whitespace_change: Option<bool>,
+
+    /// Ignore whitespace at the end of lines when comparing them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_whitespace_eol: Option<bool>,
+
+    /// Spend extra time to find the smallest possible diff.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimal: Option<bool>,
+
+    /// Use the patience diff algorithm.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub patience: Option<bool>,
+}
+
+/// Names of the flags in `MergeSettings`, as accepted by `config --set-merge-option`.
+pub const MERGE_OPTION_KEYS: &[&str] = &[
+    "find_renames",
+    "ignore_whitespace",
+    "ignore_whitespace_change",
+    "ignore_whitespace_eol",
+    "minimal",
+    "patience",
+];
+
 /// GitFlow configuration
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Config {
     /// Map of branch names to PR information.
     pub prs: HashMap<String, PrInfo>,
-    
+
     /// Default base branch (usually main or master).
     pub default_base_branch: String,
-    
+
     /// Manual branch relationships for explicit configuration.
     #[serde(default)]
     pub branch_relationships: HashMap<String, Vec<String>>,
-    
+
     /// Strategy to use for detecting branch relationships.
     #[serde(default)]
     pub branch_detection_strategy: BranchRelationStrategy,
+
+    /// Branch count above which DefaultRoot is auto-selected instead of CommitHistory
+    /// when no strategy is given on the command line.
+    #[serde(default)]
+    pub auto_strategy_threshold: Option<usize>,
+
+    /// Only follow first parents when detecting relationships by commit history.
+    #[serde(default)]
+    pub history_first_parent: bool,
+
+    /// Resolve a branch's parent as its closest ancestor branch instead of from the tree.
+    #[serde(default)]
+    pub closest_parent: bool,
+
+    /// Cache detected branch trees in `.git/<app name>/tree-cache.json`.
+    #[serde(default)]
+    pub tree_cache: bool,
+
+    /// Glob patterns (e.g. `dependabot/*`) of branches excluded from relationship detection.
+    #[serde(default)]
+    pub ignore_branches: Vec<String>,
+
+    /// Glob patterns (e.g. `feature/*`) restricting detection to matching branches; all if empty.
+    #[serde(default)]
+    pub include_branches: Vec<String>,
+
+    /// Draw the branch hierarchy with ASCII glyphs instead of box-drawing characters.
+    #[serde(default)]
+    pub ascii_tree: bool,
+
+    /// Order in which `show` lists the children of each branch.
+    #[serde(default)]
+    pub show_sort: BranchSortOrder,
+
+    /// Regex used to extract a ticket key from branch names (e.g. `^([A-Z]+)-\d+`).
+    /// The first capture group is used as the key, or the whole match if there is none.
+    #[serde(default)]
+    pub ticket_pattern: Option<String>,
+
+    /// Map of ticket keys to the base branch their branches should target.
+    #[serde(default)]
+    pub ticket_base_map: HashMap<String, String>,
+
+    /// The ticket pattern compiled on first use.
+    #[serde(skip)]
+    ticket_regex: OnceLock<Option<Regex>>,
+
+    /// Trailers (e.g. `Change-Id: ...`) appended to commit messages.
+    #[serde(default)]
+    pub commit_trailers: Vec<String>,
+
+    /// Conventional commit types accepted by `commit --type`; `DEFAULT_COMMIT_TYPES` if empty.
+    #[serde(default)]
+    pub commit_types: Vec<String>,
+
+    /// Glob patterns (e.g. `*.local.json`) of paths never staged when committing.
+    #[serde(default)]
+    pub commit_exclude: Vec<String>,
+
+    /// Similarity threshold (0-100) for rename detection during merges; git2's default if unset.
+    #[serde(default)]
+    pub merge_rename_threshold: Option<u32>,
+
+    /// List the merged commits in the body of merge commits.
+    #[serde(default)]
+    pub merge_include_commit_list: bool,
+
+    /// Standard merge flags applied when merging branches.
+    #[serde(default)]
+    pub merge_options: MergeSettings,
+
+    /// Date merge commits with the newest merged commit's author date instead of now.
+    #[serde(default)]
+    pub preserve_author_date: bool,
+
+    /// Merge into branches that are not checked out in memory, without a checkout.
+    #[serde(default)]
+    pub in_memory_merge: bool,
+
+    /// Replay conflict resolutions recorded by `git rerere` when merging, in repositories that
+    /// already have `.git/rr-cache`.
+    #[serde(default)]
+    pub use_rerere: bool,
+
+    /// Also read manual relationships from `refs/gitflow/relationships` in the repository.
+    #[serde(default)]
+    pub shared_relationships: bool,
+
+    /// Alternative names (e.g. `trunk`) mapped to real branch names.
+    #[serde(default)]
+    pub branch_aliases: HashMap<String, String>,
+
+    /// Default flag values per command (e.g. `cascade` -> `yes` -> `true`).
+    #[serde(default)]
+    pub command_defaults: HashMap<String, HashMap<String, String>>,
+
+    /// Deepest branch hierarchy level cascaded or printed; `DEFAULT_MAX_DEPTH` if unset.
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+
+    /// Seconds fetch and push wait for the remote; `DEFAULT_NETWORK_TIMEOUT_SECS` if unset.
+    #[serde(default)]
+    pub git_network_timeout_secs: Option<u64>,
+
+    /// Retries of fetch and push after transient errors; `DEFAULT_NETWORK_RETRIES` if unset.
+    #[serde(default)]
+    pub git_network_retries: Option<u32>,
+
+    /// Shell command run after each successful cascade merge, with `GITFLOW_FROM` and
+    /// `GITFLOW_TO` set to the merged branches.
+    ///
+    /// The command runs with the user's privileges and is taken verbatim from the config file,
+    /// so whoever can write that file can run arbitrary commands on every cascade. Branch names
+    /// are only passed through the environment, never interpolated into the command; quote
+    /// them (`"$GITFLOW_TO"`) when using them.
+    #[serde(default)]
+    pub post_merge_command: Option<String>,
+
+    /// Stop the cascade when the post-merge command fails instead of only warning.
+    #[serde(default)]
+    pub post_merge_abort_on_failure: bool,
+
+    /// Shell commands run in order after `create` makes a branch, with `GITFLOW_BRANCH` and
+    /// `GITFLOW_PARENT` set. They run with the user's privileges, like `post_merge_command`.
+    #[serde(default)]
+    pub create_hooks: Vec<String>,
+
+    /// Run the remaining create hooks after one fails instead of stopping at it.
+    #[serde(default)]
+    pub create_hooks_continue_on_failure: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            prs: HashMap::new(),
+            default_base_branch: "main".to_string(),
+            branch_relationships: HashMap::new(),
+            branch_detection_strategy: BranchRelationStrategy::default(),
+            auto_strategy_threshold: None,
+            history_first_parent: false,
+            closest_parent: false,
+            tree_cache: false,
+            ignore_branches: Vec::new(),
+            include_branches: Vec::new(),
+            ascii_tree: false,
+            show_sort: BranchSortOrder::default(),
+            ticket_pattern: None,
+            ticket_base_map: HashMap::new(),
+            ticket_regex: OnceLock::new(),
+            commit_trailers: Vec::new(),
+            commit_types: Vec::new(),
+            commit_exclude: Vec::new(),
+            merge_rename_threshold: None,
+            merge_include_commit_list: false,
+            merge_options: MergeSettings::default(),
+            preserve_author_date: false,
+            in_memory_merge: false,
+            use_rerere: false,
+            shared_relationships: false,
+            branch_aliases: HashMap::new(),
+            command_defaults: HashMap::new(),
+            max_depth: None,
+            git_network_timeout_secs: None,
+            git_network_retries: None,
+            post_merge_command: None,
+            post_merge_abort_on_failure: false,
+            create_hooks: Vec::new(),
+            create_hooks_continue_on_failure: false,
+        }
+    }
 }
 
 impl Config {
@@ -54,25 +292,20 @@ impl Config {
     /// ```
     pub fn load() -> Result<Self> {
         let config_path = get_config_path()?;
-        
+
         if !config_path.exists() {
             // Create default configuration if none exists.
-            let config = Config {
-                prs: HashMap::new(),
-                default_base_branch: "main".to_string(),
-                branch_relationships: HashMap::new(),
-                branch_detection_strategy: BranchRelationStrategy::default(),
-            };
+            let config = Config::default();
             config.save()?;
             return Ok(config);
         }
-        
+
         let json = fs::read_to_string(&config_path)
             .map_err(|e| GitFlowError::Config(format!("Could not read config file: {}", e)))?;
         serde_json::from_str(&json)
             .map_err(|e| GitFlowError::Config(format!("Invalid config file format: {}", e)))
     }
-    
+
     /// Save configuration to disk.
     ///
     /// # Returns
@@ -87,15 +320,16 @@ impl Config {
         let config_path = get_config_path()?;
         // Ensure the configuration directory exists.
         if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent)
-                .map_err(|e| GitFlowError::Config(format!("Could not create config directory: {}", e)))?;
+            fs::create_dir_all(parent).map_err(|e| {
+                GitFlowError::Config(format!("Could not create config directory: {}", e))
+            })?;
         }
         let json = serde_json::to_string_pretty(self)?;
         fs::write(&config_path, json)
             .map_err(|e| GitFlowError::Config(format!("Could not write config file: {}", e)))?;
         Ok(())
     }
-    
+
     /// Add a PR to the configuration.
     ///
     /// # Arguments
@@ -111,12 +345,13 @@ impl Config {
     /// ```rust
     /// // config.add_pr("feature".to_string(), pr_info)?;
     /// ```
+    #[allow(dead_code)]
     pub fn add_pr(&mut self, branch: String, pr_info: PrInfo) -> Result<()> {
         self.prs.insert(branch, pr_info);
         self.save()?;
         Ok(())
     }
-    
+
     /// Get PR information for a specific branch.
     ///
     /// # Arguments
@@ -134,7 +369,7 @@ impl Config {
     pub fn get_pr(&self, branch: &str) -> Option<&PrInfo> {
         self.prs.get(branch)
     }
-    
+
     /// Set the default base branch.
     ///
     /// # Arguments
@@ -154,7 +389,7 @@ impl Config {
         self.save()?;
         Ok(())
     }
-    
+
     /// Set the branch detection strategy.
     ///
     /// # Arguments
@@ -169,12 +404,95 @@ impl Config {
     /// ```rust
     /// // config.set_branch_detection_strategy(BranchRelationStrategy::Manual)?;
     /// ```
-    pub fn set_branch_detection_strategy(&mut self, strategy: BranchRelationStrategy) -> Result<()> {
+    pub fn set_branch_detection_strategy(
+        &mut self,
+        strategy: BranchRelationStrategy,
+    ) -> Result<()> {
         self.branch_detection_strategy = strategy;
         self.save()?;
         Ok(())
     }
-    
+
+    /// Set the branch count above which DefaultRoot is auto-selected.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The number of local branches above which DefaultRoot is used.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_auto_strategy_threshold(50)?;
+    /// ```
+    pub fn set_auto_strategy_threshold(&mut self, threshold: usize) -> Result<()> {
+        self.auto_strategy_threshold = Some(threshold);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set whether history detection only follows first parents.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to only follow first parents.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_history_first_parent(true)?;
+    /// ```
+    pub fn set_history_first_parent(&mut self, enabled: bool) -> Result<()> {
+        self.history_first_parent = enabled;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set whether a branch's parent is resolved as its closest ancestor branch.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to pick the ancestor branch with the fewest commits in between.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_closest_parent(true)?;
+    /// ```
+    pub fn set_closest_parent(&mut self, enabled: bool) -> Result<()> {
+        self.closest_parent = enabled;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set whether detected branch trees are cached between runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to enable the branch tree cache.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_tree_cache(true)?;
+    /// ```
+    pub fn set_tree_cache(&mut self, enabled: bool) -> Result<()> {
+        self.tree_cache = enabled;
+        self.save()?;
+        Ok(())
+    }
+
     /// Add a manual branch relationship.
     ///
     /// # Arguments
@@ -193,12 +511,12 @@ impl Config {
     pub fn add_branch_relationship(&mut self, parent: String, child: String) -> Result<()> {
         self.branch_relationships
             .entry(parent)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(child);
         self.save()?;
         Ok(())
     }
-    
+
     /// Remove a manual branch relationship.
     ///
     /// # Arguments
@@ -224,21 +542,1204 @@ impl Config {
         self.save()?;
         Ok(())
     }
-}
 
-/// Get the path to the configuration file.
-///
-/// # Returns
-///
-/// * `Result<PathBuf>` - The configuration file path on success.
-///
-/// # Examples
-/// ```rust
-/// // let path = get_config_path()?;
-/// ```
-pub fn get_config_path() -> Result<PathBuf> {
-    let config_dir = dirs::config_dir()
-        .ok_or_else(|| GitFlowError::Config("Could not determine config directory".to_string()))?
-        .join("gitflow");
-    Ok(config_dir.join("config.json"))
-}
\ No newline at end of file
+    /// Rename a branch everywhere the configuration refers to it.
+    ///
+    /// The default base branch, manual relationships (parents and children), tracked PRs and
+    /// ticket bases are rewritten and saved together. Entries that already exist under the new
+    /// name are kept and merged with the old ones, so renaming twice is harmless.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The current branch name.
+    /// * `new` - The new branch name.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<usize>` - The number of entries rewritten, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.rename_branch("master", "main")?;
+    /// ```
+    pub fn rename_branch(&mut self, old: &str, new: &str) -> Result<usize> {
+        let mut renamed = 0;
+
+        if self.default_base_branch == old {
+            self.default_base_branch = new.to_string();
+            renamed += 1;
+        }
+
+        if let Some(children) = self.branch_relationships.remove(old) {
+            self.branch_relationships
+                .entry(new.to_string())
+                .or_default()
+                .extend(children);
+            renamed += 1;
+        }
+        for children in self.branch_relationships.values_mut() {
+            for child in children.iter_mut() {
+                if child == old {
+                    *child = new.to_string();
+                    renamed += 1;
+                }
+            }
+            let mut seen = HashSet::new();
+            children.retain(|child| seen.insert(child.clone()));
+        }
+
+        if let Some(pr) = self.prs.remove(old) {
+            self.prs.entry(new.to_string()).or_insert(pr);
+            renamed += 1;
+        }
+
+        for base in self.ticket_base_map.values_mut() {
+            if base == old {
+                *base = new.to_string();
+                renamed += 1;
+            }
+        }
+
+        self.save()?;
+        Ok(renamed)
+    }
+
+    /// Exclude branches matching a glob pattern from relationship detection.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The glob pattern, e.g. `dependabot/*`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the pattern is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.add_ignore_branch("dependabot/*".to_string())?;
+    /// ```
+    pub fn add_ignore_branch(&mut self, pattern: String) -> Result<()> {
+        Pattern::new(&pattern)
+            .map_err(|e| GitFlowError::Config(format!("Invalid branch pattern: {}", e)))?;
+        if !self.ignore_branches.contains(&pattern) {
+            self.ignore_branches.push(pattern);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    /// Stop excluding branches matching a glob pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The glob pattern to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.remove_ignore_branch("dependabot/*")?;
+    /// ```
+    pub fn remove_ignore_branch(&mut self, pattern: &str) -> Result<()> {
+        self.ignore_branches.retain(|p| p != pattern);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Restrict relationship detection to branches matching a glob pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The glob pattern, e.g. `feature/*`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the pattern is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.add_include_branch("feature/*".to_string())?;
+    /// ```
+    pub fn add_include_branch(&mut self, pattern: String) -> Result<()> {
+        Pattern::new(&pattern)
+            .map_err(|e| GitFlowError::Config(format!("Invalid branch pattern: {}", e)))?;
+        if !self.include_branches.contains(&pattern) {
+            self.include_branches.push(pattern);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    /// Stop restricting detection to branches matching a glob pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The glob pattern to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.remove_include_branch("feature/*")?;
+    /// ```
+    pub fn remove_include_branch(&mut self, pattern: &str) -> Result<()> {
+        self.include_branches.retain(|p| p != pattern);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Replace the include patterns for this run only, e.g. from an `--only` option.
+    ///
+    /// The configuration is not saved. An empty list keeps the configured patterns.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The glob patterns to restrict detection to.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if a pattern is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.override_include_branches(&args.only)?;
+    /// ```
+    pub fn override_include_branches(&mut self, patterns: &[String]) -> Result<()> {
+        if patterns.is_empty() {
+            return Ok(());
+        }
+        for pattern in patterns {
+            Pattern::new(pattern)
+                .map_err(|e| GitFlowError::Config(format!("Invalid branch pattern: {}", e)))?;
+        }
+        self.include_branches = patterns.to_vec();
+        Ok(())
+    }
+
+    /// Set whether the branch hierarchy is drawn with ASCII glyphs.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to always use ASCII glyphs.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_ascii_tree(true)?;
+    /// ```
+    pub fn set_ascii_tree(&mut self, enabled: bool) -> Result<()> {
+        self.ascii_tree = enabled;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set the order in which `show` lists the children of each branch.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The sort order.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_show_sort(BranchSortOrder::CommitDate)?;
+    /// ```
+    pub fn set_show_sort(&mut self, order: BranchSortOrder) -> Result<()> {
+        self.show_sort = order;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set the regex used to extract ticket keys from branch names.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The regular expression to use.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the pattern is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_ticket_pattern(r"^([A-Z]+)-\d+".to_string())?;
+    /// ```
+    pub fn set_ticket_pattern(&mut self, pattern: String) -> Result<()> {
+        Regex::new(&pattern)
+            .map_err(|e| GitFlowError::Config(format!("Invalid ticket pattern: {}", e)))?;
+        self.ticket_pattern = Some(pattern);
+        self.ticket_regex = OnceLock::new();
+        self.save()?;
+        Ok(())
+    }
+
+    /// Map a ticket key to the base branch its branches should target.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticket` - The ticket key as extracted by the ticket pattern.
+    /// * `base`   - The base branch for matching branches.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.add_ticket_base("JIRA".to_string(), "develop".to_string())?;
+    /// ```
+    pub fn add_ticket_base(&mut self, ticket: String, base: String) -> Result<()> {
+        self.ticket_base_map.insert(ticket, base);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Add a trailer appended to every commit message.
+    ///
+    /// # Arguments
+    ///
+    /// * `trailer` - The trailer line, e.g. `Reviewed-by: Jane <jane@example.com>`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.add_commit_trailer("Team: platform".to_string())?;
+    /// ```
+    pub fn add_commit_trailer(&mut self, trailer: String) -> Result<()> {
+        if !self.commit_trailers.contains(&trailer) {
+            self.commit_trailers.push(trailer);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    /// Accept a conventional commit type in `commit --type`.
+    ///
+    /// The first change to the list starts from `DEFAULT_COMMIT_TYPES`, so adding a type
+    /// extends the defaults rather than replacing them.
+    ///
+    /// # Arguments
+    ///
+    /// * `commit_type` - The type, e.g. `deps`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the type is not a single word.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.add_commit_type("deps".to_string())?;
+    /// ```
+    pub fn add_commit_type(&mut self, commit_type: String) -> Result<()> {
+        if commit_type.is_empty() || !commit_type.chars().all(|c| c.is_alphanumeric() || c == '-') {
+            return Err(GitFlowError::Config(format!(
+                "Invalid commit type '{}': use letters, digits and dashes only",
+                commit_type
+            )));
+        }
+        let mut types = self.commit_types();
+        if !types.contains(&commit_type) {
+            types.push(commit_type);
+        }
+        self.commit_types = types;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Stop accepting a conventional commit type in `commit --type`.
+    ///
+    /// # Arguments
+    ///
+    /// * `commit_type` - The type to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if no type would be left.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.remove_commit_type("style")?;
+    /// ```
+    pub fn remove_commit_type(&mut self, commit_type: &str) -> Result<()> {
+        let mut types = self.commit_types();
+        types.retain(|t| t != commit_type);
+        if types.is_empty() {
+            return Err(GitFlowError::Config(
+                "At least one commit type must remain (use --unset commit_types to restore the defaults)"
+                    .to_string(),
+            ));
+        }
+        self.commit_types = types;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Get the conventional commit types accepted by `commit --type`.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The configured types, or `DEFAULT_COMMIT_TYPES` if none are configured.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let allowed = config.commit_types();
+    /// ```
+    pub fn commit_types(&self) -> Vec<String> {
+        if self.commit_types.is_empty() {
+            return DEFAULT_COMMIT_TYPES.iter().map(|t| t.to_string()).collect();
+        }
+        self.commit_types.clone()
+    }
+
+    /// Never stage paths matching a glob pattern when committing.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The glob pattern, e.g. `*.local.json`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the pattern is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.add_commit_exclude("*.local.json".to_string())?;
+    /// ```
+    pub fn add_commit_exclude(&mut self, pattern: String) -> Result<()> {
+        Pattern::new(&pattern)
+            .map_err(|e| GitFlowError::Config(format!("Invalid exclude pattern: {}", e)))?;
+        if !self.commit_exclude.contains(&pattern) {
+            self.commit_exclude.push(pattern);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    /// Stop excluding paths matching a glob pattern when committing.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The glob pattern to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.remove_commit_exclude("*.local.json")?;
+    /// ```
+    pub fn remove_commit_exclude(&mut self, pattern: &str) -> Result<()> {
+        self.commit_exclude.retain(|p| p != pattern);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set the rename detection threshold used when merging.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Similarity percentage (0-100) for two files to count as a rename.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the threshold is out of range.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_merge_rename_threshold(60)?;
+    /// ```
+    pub fn set_merge_rename_threshold(&mut self, threshold: u32) -> Result<()> {
+        if threshold > 100 {
+            return Err(GitFlowError::Config(
+                "Rename threshold must be between 0 and 100".to_string(),
+            ));
+        }
+        self.merge_rename_threshold = Some(threshold);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set whether merge commits list the merged commits in their body.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to include the commit list.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_merge_include_commit_list(true)?;
+    /// ```
+    pub fn set_merge_include_commit_list(&mut self, enabled: bool) -> Result<()> {
+        self.merge_include_commit_list = enabled;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set whether merge commits keep the author date of the merged commits.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to preserve the author date.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_preserve_author_date(true)?;
+    /// ```
+    pub fn set_preserve_author_date(&mut self, enabled: bool) -> Result<()> {
+        self.preserve_author_date = enabled;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set whether merges into branches that are not checked out skip the checkout.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to merge in memory, false to always check out the target.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_in_memory_merge(true)?;
+    /// ```
+    pub fn set_in_memory_merge(&mut self, enabled: bool) -> Result<()> {
+        self.in_memory_merge = enabled;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set whether merge conflicts are resolved from git's recorded resolutions.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to replay resolutions from `.git/rr-cache`, false to stop on every conflict.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_use_rerere(true)?;
+    /// ```
+    pub fn set_use_rerere(&mut self, enabled: bool) -> Result<()> {
+        self.use_rerere = enabled;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set the shell command run after each successful cascade merge.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command, run with `sh -c`; `GITFLOW_FROM` and `GITFLOW_TO` name the
+    ///   merged branches.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the command is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_post_merge_command("cargo test".to_string())?;
+    /// ```
+    pub fn set_post_merge_command(&mut self, command: String) -> Result<()> {
+        if command.trim().is_empty() {
+            return Err(GitFlowError::Config(
+                "Post-merge command must not be empty (use --unset post_merge_command to remove it)"
+                    .to_string(),
+            ));
+        }
+        self.post_merge_command = Some(command);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set whether a failing post-merge command stops the cascade.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to abort the cascade, false to only warn.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_post_merge_abort_on_failure(true)?;
+    /// ```
+    pub fn set_post_merge_abort_on_failure(&mut self, enabled: bool) -> Result<()> {
+        self.post_merge_abort_on_failure = enabled;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Add a shell command run after `create` makes a branch.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command, run with `sh -c`; `GITFLOW_BRANCH` and `GITFLOW_PARENT` name
+    ///   the new branch and its parent.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the command is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.add_create_hook("cp .env.example .env".to_string())?;
+    /// ```
+    pub fn add_create_hook(&mut self, command: String) -> Result<()> {
+        if command.trim().is_empty() {
+            return Err(GitFlowError::Config(
+                "Create hook must not be empty".to_string(),
+            ));
+        }
+        if !self.create_hooks.contains(&command) {
+            self.create_hooks.push(command);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    /// Remove a shell command run after `create` makes a branch.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command to remove, exactly as it was added.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.remove_create_hook("cp .env.example .env")?;
+    /// ```
+    pub fn remove_create_hook(&mut self, command: &str) -> Result<()> {
+        self.create_hooks.retain(|c| c != command);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set whether the remaining create hooks still run after one fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to run the remaining hooks, false to stop at the first failure.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_create_hooks_continue_on_failure(true)?;
+    /// ```
+    pub fn set_create_hooks_continue_on_failure(&mut self, enabled: bool) -> Result<()> {
+        self.create_hooks_continue_on_failure = enabled;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set one of the standard merge flags.
+    ///
+    /// # Arguments
+    ///
+    /// * `key`   - The flag name, one of `MERGE_OPTION_KEYS`. Dashes are accepted for underscores.
+    /// * `value` - `true` or `false`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the flag or value is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_merge_option("ignore_whitespace", "true")?;
+    /// ```
+    pub fn set_merge_option(&mut self, key: &str, value: &str) -> Result<()> {
+        let enabled: bool = value.parse().map_err(|_| {
+            GitFlowError::Config(format!(
+                "Invalid value '{}' for merge option {}: expected true or false",
+                value, key
+            ))
+        })?;
+        let options = &mut self.merge_options;
+        let flag = match key.replace('-', "_").as_str() {
+            "find_renames" => &mut options.find_renames,
+            "ignore_whitespace" => &mut options.ignore_whitespace,
+            "ignore_whitespace_change" => &mut options.ignore_whitespace_change,
+            "ignore_whitespace_eol" => &mut options.ignore_whitespace_eol,
+            "minimal" => &mut options.minimal,
+            "patience" => &mut options.patience,
+            _ => {
+                return Err(GitFlowError::Config(format!(
+                    "Unknown merge option '{}' (expected one of: {})",
+                    key,
+                    MERGE_OPTION_KEYS.join(", ")
+                )));
+            }
+        };
+        *flag = Some(enabled);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set whether the Manual strategy also reads relationships shared in the repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to read `refs/gitflow/relationships`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_shared_relationships(true)?;
+    /// ```
+    pub fn set_shared_relationships(&mut self, enabled: bool) -> Result<()> {
+        self.shared_relationships = enabled;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Add or replace a branch alias.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias`  - The alternative name, e.g. `trunk`.
+    /// * `branch` - The real branch name, e.g. `main`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.add_branch_alias("trunk".to_string(), "main".to_string())?;
+    /// ```
+    pub fn add_branch_alias(&mut self, alias: String, branch: String) -> Result<()> {
+        self.branch_aliases.insert(alias, branch);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set the default value of a command flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command name, e.g. `cascade`.
+    /// * `flag`    - The flag name without dashes, e.g. `yes`.
+    /// * `value`   - The default value, e.g. `true`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if the flag does not support defaults or the
+    ///   value is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_command_default("cascade", "yes", "true".to_string())?;
+    /// ```
+    pub fn set_command_default(&mut self, command: &str, flag: &str, value: String) -> Result<()> {
+        if !COMMAND_DEFAULT_KEYS.contains(&(command, flag)) {
+            return Err(GitFlowError::Config(format!(
+                "'{}.{}' does not support a default",
+                command, flag
+            )));
+        }
+        if flag == "strategy" {
+            parse_default_strategy(command, &value)?;
+        } else {
+            parse_default_flag(command, flag, &value)?;
+        }
+        self.command_defaults
+            .entry(command.to_string())
+            .or_default()
+            .insert(flag.to_string(), value);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set the deepest branch hierarchy level that is cascaded or printed.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The maximum depth, counting root branches as depth 0.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the depth is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_max_depth(100)?;
+    /// ```
+    pub fn set_max_depth(&mut self, depth: usize) -> Result<()> {
+        if depth == 0 {
+            return Err(GitFlowError::Config(
+                "Maximum depth must be at least 1".to_string(),
+            ));
+        }
+        self.max_depth = Some(depth);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Get the deepest branch hierarchy level that is cascaded or printed.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The configured maximum depth, or `DEFAULT_MAX_DEPTH` if unset.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let max_depth = config.max_depth();
+    /// ```
+    pub fn max_depth(&self) -> usize {
+        self.max_depth.unwrap_or(DEFAULT_MAX_DEPTH)
+    }
+
+    /// Set how long fetch and push wait for the remote before failing.
+    ///
+    /// # Arguments
+    ///
+    /// * `secs` - The timeout in seconds.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the timeout is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_git_network_timeout_secs(60)?;
+    /// ```
+    pub fn set_git_network_timeout_secs(&mut self, secs: u64) -> Result<()> {
+        if secs == 0 {
+            return Err(GitFlowError::Config(
+                "Network timeout must be at least 1 second".to_string(),
+            ));
+        }
+        self.git_network_timeout_secs = Some(secs);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Get how long fetch and push wait for the remote before failing.
+    ///
+    /// # Returns
+    ///
+    /// * `u64` - The configured timeout in seconds, or `DEFAULT_NETWORK_TIMEOUT_SECS` if unset.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let timeout = config.git_network_timeout_secs();
+    /// ```
+    pub fn git_network_timeout_secs(&self) -> u64 {
+        self.git_network_timeout_secs
+            .unwrap_or(DEFAULT_NETWORK_TIMEOUT_SECS)
+    }
+
+    /// Set how often fetch and push are retried after transient network errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `retries` - The number of retries; 0 disables retrying.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.set_git_network_retries(3)?;
+    /// ```
+    pub fn set_git_network_retries(&mut self, retries: u32) -> Result<()> {
+        self.git_network_retries = Some(retries);
+        self.save()?;
+        Ok(())
+    }
+
+    /// Get how often fetch and push are retried after transient network errors.
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - The configured retry count, or `DEFAULT_NETWORK_RETRIES` if unset.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let retries = config.git_network_retries();
+    /// ```
+    pub fn git_network_retries(&self) -> u32 {
+        self.git_network_retries.unwrap_or(DEFAULT_NETWORK_RETRIES)
+    }
+
+    /// Reset a single setting to its built-in default.
+    ///
+    /// Map and list settings are emptied and optional settings are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The setting name as stored in the config file, e.g. `default_base_branch`.
+    ///   Dashes are accepted in place of underscores.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the key is unknown.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.unset("branch_relationships")?;
+    /// ```
+    pub fn unset(&mut self, key: &str) -> Result<()> {
+        let key = key.trim().replace('-', "_");
+        let mut current = serde_json::to_value(&*self)?;
+        let default = serde_json::to_value(Config::default())?;
+
+        let (Some(fields), Some(value)) = (current.as_object_mut(), default.get(&key)) else {
+            return Err(GitFlowError::Config(format!(
+                "Unknown configuration key '{}'",
+                key
+            )));
+        };
+        fields.insert(key, value.clone());
+
+        *self = serde_json::from_value(current)?;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Write the whole configuration to a file, e.g. to share it with a team.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The destination file.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the file cannot be written.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.export(Path::new("team-gitflow.json"))?;
+    /// ```
+    pub fn export(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).map_err(|e| {
+            GitFlowError::Config(format!("Could not write {}: {}", path.display(), e))
+        })?;
+        Ok(())
+    }
+
+    /// Load settings from a file written by [`Config::export`] into this configuration.
+    ///
+    /// The file may contain only some settings. They are merged into the current configuration:
+    /// map settings (such as `branch_relationships` or `branch_aliases`) gain the imported
+    /// entries and all other settings are replaced. With `replace`, settings missing from the
+    /// file are reset to their defaults instead. Nothing is saved unless the result is valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `path`    - The file to import.
+    /// * `replace` - Start from the defaults instead of the current configuration.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or a configuration error if the file cannot be read, is
+    ///   not a configuration object, names unknown settings or holds invalid values.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.import(Path::new("team-gitflow.json"), false)?;
+    /// ```
+    pub fn import(&mut self, path: &Path, replace: bool) -> Result<()> {
+        let invalid = |reason: String| {
+            GitFlowError::Config(format!("Cannot import {}: {}", path.display(), reason))
+        };
+        let json = fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+        let imported: serde_json::Value =
+            serde_json::from_str(&json).map_err(|e| invalid(e.to_string()))?;
+        let serde_json::Value::Object(imported) = imported else {
+            return Err(invalid("expected a JSON object of settings".to_string()));
+        };
+
+        let mut merged = if replace {
+            serde_json::to_value(Config::default())?
+        } else {
+            serde_json::to_value(&*self)?
+        };
+        let Some(fields) = merged.as_object_mut() else {
+            return Err(invalid("the configuration is not an object".to_string()));
+        };
+        for (key, value) in imported {
+            match (fields.get_mut(&key), value) {
+                (None, _) => return Err(invalid(format!("unknown setting '{}'", key))),
+                (Some(serde_json::Value::Object(current)), serde_json::Value::Object(entries))
+                    if !replace =>
+                {
+                    current.extend(entries);
+                }
+                (Some(current), value) => *current = value,
+            }
+        }
+
+        let config: Config = serde_json::from_value(merged).map_err(|e| invalid(e.to_string()))?;
+        config.check().map_err(|e| match e {
+            GitFlowError::Config(reason) => invalid(reason),
+            e => e,
+        })?;
+        *self = config;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Check the settings that the setters validate, for configurations not built through them.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok if every setting is valid, or a configuration error for the first
+    ///   invalid one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.check()?;
+    /// ```
+    fn check(&self) -> Result<()> {
+        for pattern in self.ignore_branches.iter().chain(&self.include_branches) {
+            Pattern::new(pattern)
+                .map_err(|e| GitFlowError::Config(format!("Invalid branch pattern: {}", e)))?;
+        }
+        for pattern in &self.commit_exclude {
+            Pattern::new(pattern)
+                .map_err(|e| GitFlowError::Config(format!("Invalid exclude pattern: {}", e)))?;
+        }
+        if let Some(pattern) = &self.ticket_pattern {
+            Regex::new(pattern)
+                .map_err(|e| GitFlowError::Config(format!("Invalid ticket pattern: {}", e)))?;
+        }
+        if self
+            .merge_rename_threshold
+            .is_some_and(|threshold| threshold > 100)
+        {
+            return Err(GitFlowError::Config(
+                "Rename threshold must be between 0 and 100".to_string(),
+            ));
+        }
+        if self.max_depth == Some(0) || self.git_network_timeout_secs == Some(0) {
+            return Err(GitFlowError::Config(
+                "max_depth and git_network_timeout_secs must be greater than zero".to_string(),
+            ));
+        }
+        for (command, flags) in &self.command_defaults {
+            for (flag, value) in flags {
+                if !COMMAND_DEFAULT_KEYS.contains(&(command.as_str(), flag.as_str())) {
+                    return Err(GitFlowError::Config(format!(
+                        "'{}.{}' does not support a default",
+                        command, flag
+                    )));
+                }
+                if flag == "strategy" {
+                    parse_default_strategy(command, value)?;
+                } else {
+                    parse_default_flag(command, flag, value)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace the whole configuration with the built-in defaults.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok on success, or an error if saving fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // config.reset()?;
+    /// ```
+    pub fn reset(&mut self) -> Result<()> {
+        *self = Config::default();
+        self.save()?;
+        Ok(())
+    }
+
+    /// Resolve a branch name given on the command line through the configured aliases.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The branch name or alias.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The aliased branch, or `name` unchanged if it is not an alias.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let branch = config.resolve_branch_alias("trunk");
+    /// ```
+    pub fn resolve_branch_alias(&self, name: &str) -> String {
+        self.branch_aliases
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// Look up the base branch for a branch using its ticket key.
+    ///
+    /// # Arguments
+    ///
+    /// * `branch` - The branch name to extract the ticket key from.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&String>` - The mapped base branch, if the branch matches the pattern and its key is mapped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let base = config.get_ticket_base("JIRA-123-description");
+    /// ```
+    pub fn get_ticket_base(&self, branch: &str) -> Option<&String> {
+        let pattern = self
+            .ticket_regex
+            .get_or_init(|| Regex::new(self.ticket_pattern.as_deref()?).ok())
+            .as_ref()?;
+        let captures = pattern.captures(branch)?;
+        let key = captures.get(1).or_else(|| captures.get(0))?.as_str();
+        self.ticket_base_map.get(key)
+    }
+
+    /// Check the branches named in the configuration against a repository.
+    ///
+    /// The default base branch and both ends of every manual relationship must exist as a
+    /// local branch or on one of the remotes. Problems are returned rather than raised, since
+    /// the configuration is shared by all repositories and may not fit every one of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - A reference to the Git repository.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - One warning per branch that does not resolve; empty if all do.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // for warning in config.validate_against_repo(&repo) { warn!("{}", warning); }
+    /// ```
+    pub fn validate_against_repo(&self, repo: &Repository) -> Vec<String> {
+        let remotes = repo.remotes().ok();
+        let exists = |name: &str| {
+            repo.find_branch(name, BranchType::Local).is_ok()
+                || remotes
+                    .iter()
+                    .flat_map(|r| r.iter().flatten())
+                    .any(|remote| {
+                        repo.find_branch(&format!("{}/{}", remote, name), BranchType::Remote)
+                            .is_ok()
+                    })
+        };
+
+        let mut warnings = Vec::new();
+        if !exists(&self.default_base_branch) {
+            warnings.push(format!(
+                "Default base branch '{}' does not exist locally or on any remote (set it with config --default-base)",
+                self.default_base_branch
+            ));
+        }
+
+        let mut relationships: Vec<_> = self.branch_relationships.iter().collect();
+        relationships.sort();
+        for (parent, children) in relationships {
+            for branch in std::iter::once(parent).chain(children) {
+                if !exists(branch) {
+                    warnings.push(format!(
+                        "Branch '{}' in relationship {} -> [{}] does not exist (remove it with config --remove-relationship)",
+                        branch,
+                        parent,
+                        children.join(", ")
+                    ));
+                }
+            }
+        }
+        warnings
+    }
+}
+
+/// Get the directory name used for gitflow's files.
+///
+/// The name is taken from `GITFLOW_APP_NAME`, so separate instances can keep separate
+/// configuration and repository state, or avoid clashing with another tool's directory. Values
+/// that are not a plain directory name are ignored with a warning. The variable is read once.
+///
+/// # Returns
+///
+/// * `&'static str` - The directory name, `DEFAULT_APP_NAME` unless overridden.
+///
+/// # Examples
+/// ```rust
+/// // let dir = repo.path().join(app_name());
+/// ```
+pub fn app_name() -> &'static str {
+    static APP_NAME: OnceLock<String> = OnceLock::new();
+    APP_NAME.get_or_init(|| match env::var("GITFLOW_APP_NAME") {
+        Ok(name)
+            if !name.is_empty() && name != "." && name != ".." && !name.contains(['/', '\\']) =>
+        {
+            name
+        }
+        Ok(name) => {
+            warn!(
+                "Ignoring GITFLOW_APP_NAME '{}': it must be a plain directory name",
+                name
+            );
+            DEFAULT_APP_NAME.to_string()
+        }
+        Err(_) => DEFAULT_APP_NAME.to_string(),
+    })
+}
+
+/// Get the path to the configuration file.
+///
+/// # Returns
+///
+/// * `Result<PathBuf>` - The configuration file path on success.
+///
+/// # Examples
+/// ```rust
+/// // let path = get_config_path()?;
+/// ```
+pub fn get_config_path() -> Result<PathBuf> {
+    let config_dir = dirs::config_dir()
+        .ok_or_else(|| GitFlowError::Config("Could not determine config directory".to_string()))?
+        .join(app_name());
+    Ok(config_dir.join("config.json"))
+}
@@ -28,6 +28,45 @@ pub enum GitFlowError {
 
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
+
+    #[error("Push rejected: {0}")]
+    PushRejected(String),
+
+    #[error("Network error: {0}")]
+    Network(String),
+
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
+    #[error("Unexpected error: {0}")]
+    Unknown(String),
+}
+
+impl GitFlowError {
+    /// Get the name of the error variant, for machine-readable error reports.
+    ///
+    /// # Returns
+    ///
+    /// * `&'static str` - The variant name, e.g. "BranchNotFound".
+    ///
+    /// # Examples
+    /// ```rust
+    /// // assert_eq!(GitFlowError::Aborted(String::new()).kind(), "Aborted");
+    /// ```
+    pub fn kind(&self) -> &'static str {
+        match self {
+            GitFlowError::Git(_) => "Git",
+            GitFlowError::Aborted(_) => "Aborted",
+            GitFlowError::BranchNotFound(_) => "BranchNotFound",
+            GitFlowError::Config(_) => "Config",
+            GitFlowError::Io(_) => "Io",
+            GitFlowError::Serialization(_) => "Serialization",
+            GitFlowError::PushRejected(_) => "PushRejected",
+            GitFlowError::Network(_) => "Network",
+            GitFlowError::Timeout(_) => "Timeout",
+            GitFlowError::Unknown(_) => "Unknown",
+        }
+    }
 }
 
 /// Result type alias to simplify function signatures
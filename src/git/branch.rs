@@ -10,15 +10,18 @@
 
 use crate::configuration::Config;
 use crate::error::{GitFlowError, Result};
-use git2::{BranchType, Commit, Repository};
-use log::{debug, info};
+use crate::git::shared::read_shared_relationships;
+use git2::{BranchType, Commit, Oid, Repository, RepositoryState};
+use glob::Pattern;
+use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Defines the strategy to use for detecting branch relationships
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
 pub enum BranchRelationStrategy {
     /// Use commit history to determine relationships (original method)
+    #[default]
     CommitHistory,
     /// Use branch creation timestamps
     CreationTime,
@@ -28,10 +31,16 @@ pub enum BranchRelationStrategy {
     Manual,
 }
 
-impl Default for BranchRelationStrategy {
-    fn default() -> Self {
-        BranchRelationStrategy::CommitHistory
-    }
+/// Defines the order in which the children of a branch are listed
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum BranchSortOrder {
+    /// Alphabetically by branch name
+    #[default]
+    Alpha,
+    /// Most recent tip commit first
+    CommitDate,
+    /// Fewest commits ahead of the parent first
+    Topo,
 }
 
 /// Get the current branch name from the Git repository
@@ -42,7 +51,9 @@ impl Default for BranchRelationStrategy {
 ///
 /// # Returns
 ///
-/// * `Result<String>` - The current branch name on success or an error if not in a branch.
+/// * `Result<String>` - The current branch name on success or an error if not in a branch, or
+///   if a rebase, bisect or cherry-pick is in progress (HEAD does not name the branch being
+///   worked on then).
 ///
 /// # Examples
 /// ```rust
@@ -51,9 +62,25 @@ impl Default for BranchRelationStrategy {
 /// println!("Current branch: {}", branch);
 /// ```
 pub fn get_current_branch(repo: &Repository) -> Result<String> {
+    let operation = match repo.state() {
+        RepositoryState::Rebase
+        | RepositoryState::RebaseInteractive
+        | RepositoryState::RebaseMerge
+        | RepositoryState::ApplyMailboxOrRebase => Some("A rebase"),
+        RepositoryState::Bisect => Some("A bisect"),
+        RepositoryState::CherryPick | RepositoryState::CherryPickSequence => Some("A cherry-pick"),
+        _ => None,
+    };
+    if let Some(operation) = operation {
+        return Err(GitFlowError::Aborted(format!(
+            "{} is in progress; finish or abort it before running gitflow",
+            operation
+        )));
+    }
+
     let head = repo.head()?;
 
-    if (!head.is_branch()) {
+    if !head.is_branch() {
         return Err(GitFlowError::Git(git2::Error::from_str(
             "HEAD is not a branch (detached HEAD state)",
         )));
@@ -66,22 +93,39 @@ pub fn get_current_branch(repo: &Repository) -> Result<String> {
 
 /// Create a new branch based on parent and switch to it
 ///
+/// The parent may be a local branch, a remote-tracking branch such as `origin/feature` (in
+/// which case the new branch tracks it), or any revision Git can resolve.
+///
 /// # Arguments
 ///
-/// * `repo`   - The Git repository.
-/// * `name`   - The name for the new branch.
-/// * `parent` - An optional parent branch name.
+/// * `repo`    - The Git repository.
+/// * `name`    - The name for the new branch.
+/// * `parent`  - An optional parent branch name.
+/// * `dry_run` - Only check that the branch can be created and report what would be done.
 ///
 /// # Returns
 ///
-/// * `Result<()>` - Ok on success, or an error if the branch already exists or if creation fails.
+/// * `Result<()>` - Ok on success, or an error if the name is invalid, the branch already
+///   exists, the parent does not resolve or creation fails.
 ///
 /// # Examples
 /// ```rust
 /// // Create a new branch "feature" based on the current branch:
-/// create_new_branch(&repo, "feature", None)?;
+/// create_new_branch(&repo, "feature", None, false)?;
 /// ```
-pub fn create_new_branch(repo: &Repository, name: &str, parent: Option<&str>) -> Result<()> {
+pub fn create_new_branch(
+    repo: &Repository,
+    name: &str,
+    parent: Option<&str>,
+    dry_run: bool,
+) -> Result<()> {
+    if !git2::Branch::name_is_valid(name)? {
+        return Err(GitFlowError::Git(git2::Error::from_str(&format!(
+            "'{}' is not a valid branch name",
+            name
+        ))));
+    }
+
     // Check if branch already exists.
     if repo.find_branch(name, BranchType::Local).is_ok() {
         return Err(GitFlowError::Git(git2::Error::from_str(&format!(
@@ -96,16 +140,28 @@ pub fn create_new_branch(repo: &Repository, name: &str, parent: Option<&str>) ->
         None => get_current_branch(repo)?,
     };
 
-    // Locate the parent branch.
-    let parent_branch = repo
-        .find_branch(&parent_branch_name, BranchType::Local)
-        .map_err(|_| GitFlowError::BranchNotFound(parent_branch_name.clone()))?;
+    // Get the commit to base the new branch on, and the remote branch to track if any.
+    let (commit, upstream) = resolve_parent(repo, &parent_branch_name)?;
 
-    // Get the commit to base the new branch on.
-    let commit = parent_branch.get().peel_to_commit()?;
+    if dry_run {
+        info!(
+            "Would create {} from {} at {}{}",
+            name,
+            parent_branch_name,
+            commit.id(),
+            upstream
+                .as_deref()
+                .map_or_else(String::new, |upstream| format!(", tracking {}", upstream))
+        );
+        return Ok(());
+    }
 
     // Create the new branch.
-    repo.branch(name, &commit, false)?;
+    let mut branch = repo.branch(name, &commit, false)?;
+    if let Some(upstream) = &upstream {
+        branch.set_upstream(Some(upstream))?;
+        info!("Branch {} set up to track {}", name, upstream);
+    }
 
     // Checkout and set HEAD to the new branch.
     let obj = repo.revparse_single(&format!("refs/heads/{}", name))?;
@@ -116,6 +172,94 @@ pub fn create_new_branch(repo: &Repository, name: &str, parent: Option<&str>) ->
     Ok(())
 }
 
+/// Resolve the parent of a new branch to a commit
+///
+/// Local branches are tried first, then remote-tracking branches whose first path segment
+/// names a configured remote, then any revision Git can parse.
+///
+/// # Arguments
+///
+/// * `repo`   - The Git repository.
+/// * `parent` - The parent name given by the user.
+///
+/// # Returns
+///
+/// * `Result<(Commit, Option<String>)>` - The commit to branch from, and the remote branch to
+///   track when the parent is a remote-tracking branch.
+///
+/// # Examples
+/// ```rust
+/// // let (commit, upstream) = resolve_parent(&repo, "origin/feature")?;
+/// ```
+fn resolve_parent<'repo>(
+    repo: &'repo Repository,
+    parent: &str,
+) -> Result<(Commit<'repo>, Option<String>)> {
+    if let Ok(branch) = repo.find_branch(parent, BranchType::Local) {
+        return Ok((branch.get().peel_to_commit()?, None));
+    }
+
+    if let Some((remote, _)) = parent.split_once('/')
+        && repo.remotes()?.iter().flatten().any(|name| name == remote)
+    {
+        match repo.find_branch(parent, BranchType::Remote) {
+            Ok(branch) => return Ok((branch.get().peel_to_commit()?, Some(parent.to_string()))),
+            Err(_) => debug!("No remote branch {}, trying it as a revision", parent),
+        }
+    }
+
+    match repo
+        .revparse_single(parent)
+        .and_then(|obj| obj.peel_to_commit())
+    {
+        Ok(commit) => Ok((commit, None)),
+        Err(_) => Err(GitFlowError::BranchNotFound(parent.to_string())),
+    }
+}
+
+/// Select the detection strategy to use when none was given on the command line
+///
+/// When `auto_strategy_threshold` is set and the configured strategy is still the default, the
+/// number of local branches decides: above the threshold the cheap DefaultRoot strategy is
+/// used, otherwise CommitHistory. A strategy that was configured explicitly, such as Manual,
+/// is always returned as is.
+///
+/// # Arguments
+///
+/// * `repo`   - The repository reference.
+/// * `config` - Reference to the configuration settings.
+///
+/// # Returns
+///
+/// * `Result<BranchRelationStrategy>` - The strategy to use.
+///
+/// # Examples
+/// ```rust
+/// // let strategy = select_strategy(&repo, &config)?;
+/// ```
+pub fn select_strategy(repo: &Repository, config: &Config) -> Result<BranchRelationStrategy> {
+    let threshold = match config.auto_strategy_threshold {
+        Some(threshold)
+            if config.branch_detection_strategy == BranchRelationStrategy::default() =>
+        {
+            threshold
+        }
+        _ => return Ok(config.branch_detection_strategy),
+    };
+
+    let count = repo.branches(Some(BranchType::Local))?.count();
+    let strategy = if count > threshold {
+        BranchRelationStrategy::DefaultRoot
+    } else {
+        BranchRelationStrategy::CommitHistory
+    };
+    info!(
+        "Auto-selected {:?} strategy: {} local branches, threshold {}",
+        strategy, count, threshold
+    );
+    Ok(strategy)
+}
+
 /// Build a tree of branches showing parent-child relationships
 ///
 /// # Arguments
@@ -138,24 +282,142 @@ pub fn get_branch_tree(
     strategy: BranchRelationStrategy,
     config: &Config,
 ) -> Result<HashMap<String, Vec<String>>> {
+    // History walks stop at the shallow boundary, so detected relationships can be wrong.
+    if repo.is_shallow()
+        && matches!(
+            strategy,
+            BranchRelationStrategy::CommitHistory | BranchRelationStrategy::CreationTime
+        )
+    {
+        warn!(
+            "Repository is a shallow clone; {:?} relationships may be incomplete. \
+             Run 'git fetch --unshallow' or use the default or manual strategy.",
+            strategy
+        );
+    }
+
     match strategy {
-        BranchRelationStrategy::CommitHistory => get_branch_tree_by_history(repo),
-        BranchRelationStrategy::CreationTime => get_branch_tree_by_creation_time(repo),
-        BranchRelationStrategy::DefaultRoot => {
-            get_branch_tree_with_default_root(repo, &config.default_base_branch)
+        BranchRelationStrategy::CommitHistory => get_branch_tree_by_history(
+            repo,
+            config.history_first_parent,
+            &config.include_branches,
+            &config.ignore_branches,
+        ),
+        BranchRelationStrategy::CreationTime => get_branch_tree_by_creation_time(
+            repo,
+            &config.include_branches,
+            &config.ignore_branches,
+        ),
+        BranchRelationStrategy::DefaultRoot => get_branch_tree_with_default_root(
+            repo,
+            &config.default_base_branch,
+            &config.include_branches,
+            &config.ignore_branches,
+        ),
+        BranchRelationStrategy::Manual => {
+            // Relationships shared through the repository extend the configured ones.
+            let mut relationships = config.branch_relationships.clone();
+            if config.shared_relationships {
+                for (parent, children) in read_shared_relationships(repo)? {
+                    let entry = relationships.entry(parent).or_default();
+                    for child in children {
+                        if !entry.contains(&child) {
+                            entry.push(child);
+                        }
+                    }
+                }
+            }
+
+            let detected = |name: &str| {
+                is_detected_branch(&config.include_branches, &config.ignore_branches, name)
+            };
+            let mut tree = HashMap::new();
+            for (parent, children) in &relationships {
+                if !detected(parent) {
+                    continue;
+                }
+                let children: Vec<String> = children
+                    .iter()
+                    .filter(|child| detected(child))
+                    .cloned()
+                    .collect();
+                if !children.is_empty() {
+                    tree.insert(parent.clone(), children);
+                }
+            }
+            Ok(tree)
         }
-        BranchRelationStrategy::Manual => Ok(config.branch_relationships.clone()),
     }
 }
 
+/// Check whether a branch matches any of the ignore glob patterns
+///
+/// # Arguments
+///
+/// * `patterns` - Glob patterns such as `dependabot/*`.
+/// * `name`     - The branch name to check.
+///
+/// # Returns
+///
+/// * `bool` - True if the branch should be excluded from detection.
+///
+/// # Examples
+/// ```rust
+/// // assert!(is_ignored_branch(&["dependabot/*".to_string()], "dependabot/npm/lodash"));
+/// ```
+pub fn is_ignored_branch(patterns: &[String], name: &str) -> bool {
+    matches_any_pattern(patterns, name)
+}
+
+/// Check whether a branch takes part in relationship detection
+///
+/// Include patterns are applied first: when any are set, only matching branches are kept.
+/// Ignore patterns then exclude branches from what remains.
+///
+/// # Arguments
+///
+/// * `include` - Glob patterns such as `feature/*`; all branches are included if empty.
+/// * `ignore`  - Glob patterns such as `dependabot/*`.
+/// * `name`    - The branch name to check.
+///
+/// # Returns
+///
+/// * `bool` - True if the branch matches an include pattern (or there are none) and no ignore
+///   pattern.
+///
+/// # Examples
+/// ```rust
+/// // assert!(is_detected_branch(&["feature/*".to_string()], &[], "feature/login"));
+/// ```
+pub fn is_detected_branch(include: &[String], ignore: &[String], name: &str) -> bool {
+    (include.is_empty() || matches_any_pattern(include, name)) && !is_ignored_branch(ignore, name)
+}
+
+/// Check whether a name matches any of the glob patterns; invalid patterns never match.
+fn matches_any_pattern(patterns: &[String], name: &str) -> bool {
+    patterns.iter().any(|pattern| {
+        Pattern::new(pattern)
+            .map(|pattern| pattern.matches(name))
+            .unwrap_or(false)
+    })
+}
+
 /// Build branch tree using commit history (original method)
 ///
 /// # Arguments
 /// * repo - Reference to the Git repository.
-/// 
+/// * first_parent - Only follow first parents when checking ancestry.
+/// * include - Glob patterns of branches to consider; all branches if empty.
+/// * ignore - Glob patterns of branches to leave out.
+///
 /// # Returns
 /// A Result containing a HashMap mapping parent branches to their child branch lists.
-fn get_branch_tree_by_history(repo: &Repository) -> Result<HashMap<String, Vec<String>>> {
+fn get_branch_tree_by_history(
+    repo: &Repository,
+    first_parent: bool,
+    include: &[String],
+    ignore: &[String],
+) -> Result<HashMap<String, Vec<String>>> {
     let mut tree = HashMap::new();
     let branches = repo.branches(Some(BranchType::Local))?;
 
@@ -169,7 +431,9 @@ fn get_branch_tree_by_history(repo: &Repository) -> Result<HashMap<String, Vec<S
                 GitFlowError::Git(git2::Error::from_str("Invalid UTF-8 in branch name"))
             })?
             .to_string();
-        all_branches.push(name);
+        if is_detected_branch(include, ignore, &name) {
+            all_branches.push(name);
+        }
     }
 
     // Second pass: build the parent-child relationships.
@@ -181,8 +445,14 @@ fn get_branch_tree_by_history(repo: &Repository) -> Result<HashMap<String, Vec<S
             }
             let other_commit = repo.revparse_single(other_branch)?.peel_to_commit()?;
             // Determine if 'other_branch' is a descendant of 'branch_name'
-            if is_descendant_of(repo, &other_commit, &commit)?
-                && !is_direct_parent_child(&all_branches, branch_name, other_branch, repo)?
+            if is_descendant_of(repo, &other_commit, &commit, first_parent)?
+                && !is_direct_parent_child(
+                    &all_branches,
+                    branch_name,
+                    other_branch,
+                    repo,
+                    first_parent,
+                )?
             {
                 tree.entry(branch_name.clone())
                     .or_insert_with(Vec::new)
@@ -199,10 +469,16 @@ fn get_branch_tree_by_history(repo: &Repository) -> Result<HashMap<String, Vec<S
 ///
 /// # Arguments
 /// * repo - Reference to the Git repository.
-/// 
+/// * include - Glob patterns of branches to consider; all branches if empty.
+/// * ignore - Glob patterns of branches to leave out.
+///
 /// # Returns
 /// A Result containing a HashMap mapping parent branches to their child branch lists.
-fn get_branch_tree_by_creation_time(repo: &Repository) -> Result<HashMap<String, Vec<String>>> {
+fn get_branch_tree_by_creation_time(
+    repo: &Repository,
+    include: &[String],
+    ignore: &[String],
+) -> Result<HashMap<String, Vec<String>>> {
     let mut tree = HashMap::new();
     let branches = repo.branches(Some(BranchType::Local))?;
 
@@ -211,6 +487,9 @@ fn get_branch_tree_by_creation_time(repo: &Repository) -> Result<HashMap<String,
     for branch_result in branches {
         let (branch, _) = branch_result?;
         let name = branch.name()?.unwrap_or("").to_string();
+        if !is_detected_branch(include, ignore, &name) {
+            continue;
+        }
         if let Ok(commit) = get_first_commit_on_branch(repo, &name) {
             let time = commit.time().seconds();
             branch_times.push((name, time));
@@ -218,7 +497,7 @@ fn get_branch_tree_by_creation_time(repo: &Repository) -> Result<HashMap<String,
     }
 
     // Sort branches by creation time (oldest first).
-    branch_times.sort_by(|a, b| a.1.cmp(&b.1));
+    branch_times.sort_by_key(|b| b.1);
     debug!("Branches by creation time: {:?}", branch_times);
 
     // Determine parent-child relationships based on time proximity.
@@ -250,12 +529,16 @@ fn get_branch_tree_by_creation_time(repo: &Repository) -> Result<HashMap<String,
 /// # Arguments
 /// * repo - Reference to the Git repository.
 /// * default_branch - The branch to use as the root for others.
-/// 
+/// * include - Glob patterns of branches to place under the root; all branches if empty.
+/// * ignore - Glob patterns of branches to leave out.
+///
 /// # Returns
 /// A Result containing a HashMap with the default branch mapped to all other branches.
 fn get_branch_tree_with_default_root(
     repo: &Repository,
     default_branch: &str,
+    include: &[String],
+    ignore: &[String],
 ) -> Result<HashMap<String, Vec<String>>> {
     let mut tree = HashMap::new();
     let branches = repo.branches(Some(BranchType::Local))?;
@@ -270,7 +553,7 @@ fn get_branch_tree_with_default_root(
     for branch_result in branches {
         let (branch, _) = branch_result?;
         let name = branch.name()?.unwrap_or("").to_string();
-        if name != default_branch {
+        if name != default_branch && is_detected_branch(include, ignore, &name) {
             other_branches.push(name);
         }
     }
@@ -291,7 +574,8 @@ fn get_branch_tree_with_default_root(
 /// * parent - The candidate parent branch name.
 /// * child - The candidate child branch name.
 /// * repo - The repository reference.
-/// 
+/// * first_parent - Only follow first parents when checking ancestry.
+///
 /// # Returns
 /// A Result with true if it's a direct parent-child relationship, false otherwise.
 pub fn is_direct_parent_child(
@@ -299,6 +583,7 @@ pub fn is_direct_parent_child(
     parent: &str,
     child: &str,
     repo: &Repository,
+    first_parent: bool,
 ) -> Result<bool> {
     let parent_commit = repo.revparse_single(parent)?.peel_to_commit()?;
     let child_commit = repo.revparse_single(child)?.peel_to_commit()?;
@@ -307,8 +592,8 @@ pub fn is_direct_parent_child(
     for other in all_branches {
         if other != parent && other != child {
             let other_commit = repo.revparse_single(other)?.peel_to_commit()?;
-            if is_descendant_of(repo, &other_commit, &parent_commit)?
-                && is_descendant_of(repo, &child_commit, &other_commit)?
+            if is_descendant_of(repo, &other_commit, &parent_commit, first_parent)?
+                && is_descendant_of(repo, &child_commit, &other_commit, first_parent)?
             {
                 return Ok(false);
             }
@@ -319,17 +604,23 @@ pub fn is_direct_parent_child(
 
 /// Check if 'commit' is a descendant of 'potential_ancestor'
 ///
+/// With `first_parent` set, only the first parent of each commit is followed. This ignores
+/// history brought in by merge commits, so merging one branch into another no longer makes
+/// them look related, at the cost of missing branches that were only joined through a merge.
+///
 /// # Arguments
 /// * repo - The repository reference.
 /// * commit - The commit to evaluate.
 /// * potential_ancestor - The commit considered as an ancestor candidate.
-/// 
+/// * first_parent - Only follow first parents (`commit.parent(0)`) when walking history.
+///
 /// # Returns
 /// A Result with true if 'commit' is a descendant, false otherwise.
 pub fn is_descendant_of(
     repo: &Repository,
     commit: &Commit,
     potential_ancestor: &Commit,
+    first_parent: bool,
 ) -> Result<bool> {
     if commit.id() == potential_ancestor.id() {
         return Ok(false); // The commits are identical.
@@ -338,6 +629,9 @@ pub fn is_descendant_of(
     let mut revwalk = repo.revwalk()?;
     revwalk.set_sorting(git2::Sort::TIME)?;
     revwalk.push(commit.id())?;
+    if first_parent {
+        revwalk.simplify_first_parent()?;
+    }
 
     for ancestor_id in revwalk {
         let ancestor_id = ancestor_id?;
@@ -354,7 +648,7 @@ pub fn is_descendant_of(
 /// * repo - The repository reference.
 /// * branch1 - The first branch name.
 /// * branch2 - The second branch name.
-/// 
+///
 /// # Returns
 /// A Result with true if the branches are related, false otherwise.
 fn are_branches_related(repo: &Repository, branch1: &str, branch2: &str) -> Result<bool> {
@@ -362,7 +656,9 @@ fn are_branches_related(repo: &Repository, branch1: &str, branch2: &str) -> Resu
     let commit2 = repo.revparse_single(branch2)?.peel_to_commit()?;
 
     // Check if one commit is an ancestor of the other.
-    if is_descendant_of(repo, &commit1, &commit2)? || is_descendant_of(repo, &commit2, &commit1)? {
+    if is_descendant_of(repo, &commit1, &commit2, false)?
+        || is_descendant_of(repo, &commit2, &commit1, false)?
+    {
         return Ok(true);
     }
 
@@ -389,13 +685,18 @@ fn get_first_commit_on_branch<'repo>(
     Ok(commit)
 }
 
-/// Get the parent branch of the current branch using history and creation time strategies
+/// Get the parent branch of the current branch using ticket, history and creation time strategies
+///
+/// The ticket base map is consulted first so that teams can route branches by naming convention;
+/// history and creation time detection are only used when no ticket mapping applies. With
+/// `closest_parent` enabled, the closest ancestor branch is used instead of the detected trees,
+/// which picks the most specific parent in deep stacks.
 ///
 /// # Arguments
 ///
 /// * `repo`           - The repository.
 /// * `current_branch` - The current branch name.
-/// * `default_base`   - The default branch to use if no parent is found.
+/// * `config`         - Configuration providing the ticket mapping and default base branch.
 ///
 /// # Returns
 ///
@@ -403,27 +704,136 @@ fn get_first_commit_on_branch<'repo>(
 ///
 /// # Examples
 /// ```rust
-/// // Retrieve the parent branch for "feature" with default "main":
-/// let parent = get_parent_branch(&repo, "feature", "main")?;
+/// // Retrieve the parent branch for "feature":
+/// let parent = get_parent_branch(&repo, "feature", &config)?;
 /// ```
 pub fn get_parent_branch(
     repo: &Repository,
     current_branch: &str,
-    default_base: &str,
+    config: &Config,
 ) -> Result<String> {
-    let branch_tree = get_branch_tree_by_history(repo)?;
+    if let Some(base) = config.get_ticket_base(current_branch) {
+        debug!("Using ticket base {} for {}", base, current_branch);
+        return Ok(base.clone());
+    }
+    if config.closest_parent {
+        let tip = repo
+            .find_branch(current_branch, BranchType::Local)?
+            .get()
+            .peel_to_commit()?
+            .id();
+        let parent = nearest_ancestor_branch(repo, tip, Some(current_branch), config)?;
+        return Ok(parent.unwrap_or_else(|| config.default_base_branch.clone()));
+    }
+    let branch_tree = get_branch_tree_by_history(
+        repo,
+        config.history_first_parent,
+        &config.include_branches,
+        &config.ignore_branches,
+    )?;
     for (parent, children) in &branch_tree {
         if children.contains(&current_branch.to_string()) {
             return Ok(parent.clone());
         }
     }
-    let branch_tree_by_time = get_branch_tree_by_creation_time(repo)?;
+    let branch_tree_by_time =
+        get_branch_tree_by_creation_time(repo, &config.include_branches, &config.ignore_branches)?;
     for (parent, children) in &branch_tree_by_time {
         if children.contains(&current_branch.to_string()) {
             return Ok(parent.clone());
         }
     }
-    Ok(default_base.to_string())
+    Ok(config.default_base_branch.clone())
+}
+
+/// Infer the most likely parent for a new branch created from HEAD.
+///
+/// The parent is the nearest local branch whose tip is an ancestor of HEAD, measured by the
+/// number of commits between the two. The current branch, ignored branches and other branches
+/// at the same commit as HEAD are never chosen, and the default base branch wins ties. When no
+/// branch qualifies, the default base branch is used.
+///
+/// # Arguments
+///
+/// * `repo`   - The repository.
+/// * `config` - Configuration providing the default base branch and ignore patterns.
+///
+/// # Returns
+///
+/// * `Result<String>` - The name of the inferred parent branch.
+///
+/// # Examples
+/// ```rust
+/// // let parent = infer_parent(&repo, &config)?;
+/// ```
+pub fn infer_parent(repo: &Repository, config: &Config) -> Result<String> {
+    let head = repo.head()?.peel_to_commit()?.id();
+    let current = get_current_branch(repo).ok();
+
+    let parent = nearest_ancestor_branch(repo, head, current.as_deref(), config)?;
+    Ok(parent.unwrap_or_else(|| config.default_base_branch.clone()))
+}
+
+/// Find the local branch whose tip is the closest ancestor of a commit.
+///
+/// Distance is the number of commits the commit is ahead of the branch tip. Ignored branches
+/// are never chosen, and the default base branch wins ties. Branches pointing at the commit
+/// itself are skipped, except for the default base branch.
+///
+/// # Arguments
+///
+/// * `repo`    - The repository.
+/// * `tip`     - The commit to find the closest ancestor branch of.
+/// * `exclude` - A branch never chosen, usually the branch `tip` belongs to.
+/// * `config`  - Configuration providing the default base branch and ignore patterns.
+///
+/// # Returns
+///
+/// * `Result<Option<String>>` - The closest ancestor branch, or None if no branch qualifies.
+fn nearest_ancestor_branch(
+    repo: &Repository,
+    tip: Oid,
+    exclude: Option<&str>,
+    config: &Config,
+) -> Result<Option<String>> {
+    let mut best: Option<(usize, bool, String)> = None;
+    for branch_result in repo.branches(Some(BranchType::Local))? {
+        let (branch, _) = branch_result?;
+        let name = match branch.name()? {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        if exclude == Some(name.as_str()) || is_ignored_branch(&config.ignore_branches, &name) {
+            continue;
+        }
+        let Some(candidate_tip) = branch.get().target() else {
+            continue;
+        };
+        // A branch at the same commit is as likely a sibling or child as the parent, so only
+        // the default base may be chosen at distance 0.
+        if candidate_tip == tip {
+            if name != config.default_base_branch {
+                continue;
+            }
+        } else if !repo.graph_descendant_of(tip, candidate_tip)? {
+            continue;
+        }
+
+        let (distance, _) = repo.graph_ahead_behind(tip, candidate_tip)?;
+        // Sort key: fewest commits away, then the default base, then by name.
+        let candidate = (distance, name != config.default_base_branch, name);
+        if best.as_ref().is_none_or(|b| candidate < *b) {
+            best = Some(candidate);
+        }
+    }
+
+    Ok(best.map(|(distance, _, name)| {
+        debug!(
+            "Nearest ancestor branch is {} ({} commits away)",
+            name, distance
+        );
+        name
+    }))
 }
 
 /// Checkout a branch by its name
@@ -450,6 +860,59 @@ pub fn checkout_branch(repo: &Repository, branch_name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Get the description of a branch from `branch.<name>.description`
+///
+/// # Arguments
+///
+/// * `repo`        - The repository.
+/// * `branch_name` - The branch name.
+///
+/// # Returns
+///
+/// * `Option<String>` - The description, or None if the branch has none.
+///
+/// # Examples
+/// ```rust
+/// // let description = get_branch_description(&repo, "feature");
+/// ```
+pub fn get_branch_description(repo: &Repository, branch_name: &str) -> Option<String> {
+    repo.config()
+        .ok()?
+        .get_string(&format!("branch.{}.description", branch_name))
+        .ok()
+        .filter(|description| !description.trim().is_empty())
+}
+
+/// Set the description of a branch in `branch.<name>.description`
+///
+/// # Arguments
+///
+/// * `repo`        - The repository.
+/// * `branch_name` - The branch name.
+/// * `description` - The description text.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the branch does not exist.
+///
+/// # Examples
+/// ```rust
+/// // set_branch_description(&repo, "feature", "Adds the new login flow")?;
+/// ```
+pub fn set_branch_description(
+    repo: &Repository,
+    branch_name: &str,
+    description: &str,
+) -> Result<()> {
+    if repo.find_branch(branch_name, BranchType::Local).is_err() {
+        return Err(GitFlowError::BranchNotFound(branch_name.to_string()));
+    }
+    repo.config()?
+        .set_str(&format!("branch.{}.description", branch_name), description)?;
+    debug!("Set description of {}", branch_name);
+    Ok(())
+}
+
 /// Find root branches (those without any parent branch)
 ///
 /// # Arguments
@@ -468,6 +931,224 @@ pub fn find_root_branches(branch_tree: &HashMap<String, Vec<String>>) -> Vec<Str
         .collect()
 }
 
+/// Sort sibling branches in place
+///
+/// Ties, and branches whose commits cannot be read, fall back to alphabetical order so the
+/// result is always deterministic.
+///
+/// # Arguments
+///
+/// * `repo`     - The repository.
+/// * `branches` - The sibling branches to sort.
+/// * `parent`   - Their parent, used by `Topo`; root branches have none and sort alphabetically.
+/// * `order`    - The order to sort them in.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the commit graph cannot be walked.
+///
+/// # Examples
+/// ```rust
+/// // sort_branches(&repo, &mut roots, None, BranchSortOrder::CommitDate)?;
+/// ```
+pub fn sort_branches(
+    repo: &Repository,
+    branches: &mut [String],
+    parent: Option<&str>,
+    order: BranchSortOrder,
+) -> Result<()> {
+    let tip = |name: &str| get_branch_commit(repo, name).ok();
+    let mut keys = HashMap::new();
+    for name in branches.iter() {
+        let key = match order {
+            BranchSortOrder::Alpha => 0,
+            // Negated so that the newest commit sorts first.
+            BranchSortOrder::CommitDate => tip(name).map_or(0, |commit| -commit.time().seconds()),
+            BranchSortOrder::Topo => match (tip(name), parent.and_then(tip)) {
+                (Some(child), Some(parent)) => {
+                    repo.graph_ahead_behind(child.id(), parent.id())?.0 as i64
+                }
+                _ => 0,
+            },
+        };
+        keys.insert(name.clone(), key);
+    }
+    branches.sort_by(|a, b| keys[a].cmp(&keys[b]).then_with(|| a.cmp(b)));
+    Ok(())
+}
+
+/// Sort the children of every branch in a branch tree
+///
+/// # Arguments
+///
+/// * `repo`        - The repository.
+/// * `branch_tree` - A branch tree mapping parents to their children.
+/// * `order`       - The order to sort the children in (see [`sort_branches`]).
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the commit graph cannot be walked.
+///
+/// # Examples
+/// ```rust
+/// // sort_branch_tree(&repo, &mut tree, config.show_sort)?;
+/// ```
+pub fn sort_branch_tree(
+    repo: &Repository,
+    branch_tree: &mut HashMap<String, Vec<String>>,
+    order: BranchSortOrder,
+) -> Result<()> {
+    for (parent, children) in branch_tree.iter_mut() {
+        sort_branches(repo, children, Some(parent), order)?;
+    }
+    Ok(())
+}
+
+/// Restrict a branch tree to a branch and its descendants
+///
+/// # Arguments
+///
+/// * `branch_tree` - A branch tree mapping parents to their children.
+/// * `root`        - The branch whose subtree is kept.
+///
+/// # Returns
+///
+/// * `Option<HashMap<String, Vec<String>>>` - The subtree rooted at `root`, or None if the
+///   branch is not part of the tree.
+///
+/// # Examples
+/// ```rust
+/// // let stack = get_subtree(&branch_tree, "feature").unwrap_or_default();
+/// ```
+pub fn get_subtree(
+    branch_tree: &HashMap<String, Vec<String>>,
+    root: &str,
+) -> Option<HashMap<String, Vec<String>>> {
+    let in_tree = branch_tree.contains_key(root)
+        || branch_tree
+            .values()
+            .any(|children| children.iter().any(|c| c == root));
+    if !in_tree {
+        return None;
+    }
+
+    let mut subtree = HashMap::new();
+    let mut queue = VecDeque::from([root.to_string()]);
+    while let Some(branch) = queue.pop_front() {
+        if subtree.contains_key(&branch) {
+            continue;
+        }
+        if let Some(children) = branch_tree.get(&branch) {
+            queue.extend(children.iter().cloned());
+            subtree.insert(branch, children.clone());
+        }
+    }
+    Some(subtree)
+}
+
+/// Order the branches of a tree so that every parent comes before its children
+///
+/// # Arguments
+///
+/// * `branch_tree` - A branch tree mapping parents to their children.
+///
+/// # Returns
+///
+/// * `Vec<String>` - All branches in the tree, parents first.
+///
+/// # Examples
+/// ```rust
+/// // let ordered = topological_order(&branch_tree);
+/// ```
+pub fn topological_order(branch_tree: &HashMap<String, Vec<String>>) -> Vec<String> {
+    let mut roots = find_root_branches(branch_tree);
+    roots.sort();
+
+    let mut ordered = Vec::new();
+    let mut visited = HashSet::new();
+    let mut queue: VecDeque<String> = roots.into_iter().collect();
+    while let Some(branch) = queue.pop_front() {
+        if !visited.insert(branch.clone()) {
+            continue;
+        }
+        if let Some(children) = branch_tree.get(&branch) {
+            queue.extend(children.iter().cloned());
+        }
+        ordered.push(branch);
+    }
+    ordered
+}
+
+/// Find the cycles in a branch tree
+///
+/// A cycle makes a branch its own ancestor, which the tree walks in `show` and `cascade` can
+/// only stop at the maximum depth. Each cycle found is reported once, starting at its
+/// alphabetically first branch.
+///
+/// # Arguments
+///
+/// * `branch_tree` - A branch tree mapping parents to their children.
+///
+/// # Returns
+///
+/// * `Vec<Vec<String>>` - The branches of each cycle in parent-to-child order, sorted; empty if
+///   the tree is acyclic.
+///
+/// # Examples
+/// ```rust
+/// // for cycle in find_cycles(&branch_tree) { warn!("Cycle: {}", cycle.join(" -> ")); }
+/// ```
+pub fn find_cycles(branch_tree: &HashMap<String, Vec<String>>) -> Vec<Vec<String>> {
+    let mut branches: Vec<&String> = branch_tree.keys().collect();
+    branches.sort();
+
+    let mut done = HashSet::new();
+    let mut cycles = HashSet::new();
+    for branch in branches {
+        collect_cycles(branch, branch_tree, &mut Vec::new(), &mut done, &mut cycles);
+    }
+    let mut cycles: Vec<Vec<String>> = cycles.into_iter().collect();
+    cycles.sort();
+    cycles
+}
+
+/// Walk a branch tree depth-first from a branch, recording the cycles it closes
+///
+/// # Arguments
+///
+/// * `branch`      - The branch to visit.
+/// * `branch_tree` - A branch tree mapping parents to their children.
+/// * `path`        - The branches from the starting branch down to `branch`'s parent.
+/// * `done`        - Branches whose descendants were fully walked already.
+/// * `cycles`      - Collects the cycles, rotated to start at their first branch.
+fn collect_cycles(
+    branch: &str,
+    branch_tree: &HashMap<String, Vec<String>>,
+    path: &mut Vec<String>,
+    done: &mut HashSet<String>,
+    cycles: &mut HashSet<Vec<String>>,
+) {
+    if let Some(start) = path.iter().position(|b| b == branch) {
+        // Rotate so that the same cycle reached from another branch compares equal.
+        let mut cycle = path[start..].to_vec();
+        let first = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap_or(0);
+        cycle.rotate_left(first);
+        cycles.insert(cycle);
+        return;
+    }
+    if done.contains(branch) {
+        return;
+    }
+    path.push(branch.to_string());
+    if let Some(children) = branch_tree.get(branch) {
+        for child in children {
+            collect_cycles(child, branch_tree, path, done, cycles);
+        }
+    }
+    path.pop();
+    done.insert(branch.to_string());
+}
+
 /// Get the latest commit for a branch
 ///
 /// # Arguments
@@ -491,3 +1172,104 @@ pub fn get_branch_commit<'repo>(
     let obj = repo.revparse_single(branch_name)?;
     obj.peel_to_commit().map_err(GitFlowError::Git)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::testing::TestRepo;
+    use std::fs;
+
+    fn patterns(patterns: &[&str]) -> Vec<String> {
+        patterns.iter().map(|pattern| pattern.to_string()).collect()
+    }
+
+    #[test]
+    fn empty_include_detects_every_branch() {
+        assert!(is_detected_branch(&[], &[], "main"));
+        assert!(is_detected_branch(&[], &[], "feature/login"));
+    }
+
+    #[test]
+    fn include_keeps_only_matching_branches() {
+        let include = patterns(&["feature/*"]);
+        assert!(is_detected_branch(&include, &[], "feature/login"));
+        assert!(!is_detected_branch(&include, &[], "release/1.0"));
+    }
+
+    #[test]
+    fn ignore_wins_over_include() {
+        let include = patterns(&["feature/*"]);
+        let ignore = patterns(&["feature/wip-*"]);
+        assert!(is_detected_branch(&include, &ignore, "feature/login"));
+        assert!(!is_detected_branch(&include, &ignore, "feature/wip-login"));
+        assert!(!is_detected_branch(&[], &ignore, "feature/wip-login"));
+    }
+
+    #[test]
+    fn current_branch_is_refused_during_a_rebase_or_bisect() {
+        let test = TestRepo::new();
+        test.commit_file("base.txt", "base\n", "base");
+        assert_eq!(get_current_branch(&test.repo).unwrap(), "main");
+
+        fs::create_dir(test.repo.path().join("rebase-merge")).unwrap();
+        let err = get_current_branch(&test.repo).unwrap_err();
+        assert!(matches!(err, GitFlowError::Aborted(ref msg) if msg.starts_with("A rebase")));
+        fs::remove_dir(test.repo.path().join("rebase-merge")).unwrap();
+
+        fs::write(test.repo.path().join("BISECT_LOG"), "").unwrap();
+        let err = get_current_branch(&test.repo).unwrap_err();
+        assert!(matches!(err, GitFlowError::Aborted(ref msg) if msg.starts_with("A bisect")));
+    }
+
+    #[test]
+    fn branches_at_the_same_commit_are_not_inferred_as_parent() {
+        let test = TestRepo::new();
+        test.commit_file("base.txt", "base\n", "base");
+        test.branch("JIRA-12-x");
+
+        assert_eq!(
+            infer_parent(&test.repo, &Config::default()).unwrap(),
+            "main"
+        );
+    }
+
+    #[test]
+    fn closest_parent_skips_a_sibling_at_the_same_tip() {
+        let test = TestRepo::new();
+        test.commit_file("base.txt", "base\n", "base");
+        test.branch("parent");
+        test.checkout("parent");
+        test.commit_file("parent.txt", "parent\n", "parent work");
+        test.branch("feature");
+        test.checkout("feature");
+        test.commit_file("feature.txt", "feature\n", "feature work");
+        test.branch("sibling");
+
+        let mut config = Config::default();
+        config.closest_parent = true;
+        assert_eq!(
+            get_parent_branch(&test.repo, "feature", &config).unwrap(),
+            "parent"
+        );
+    }
+
+    #[test]
+    fn auto_threshold_does_not_override_an_explicit_strategy() {
+        let test = TestRepo::new();
+        test.commit_file("base.txt", "base\n", "base");
+        test.branch("feature");
+
+        let mut config = Config::default();
+        config.auto_strategy_threshold = Some(1);
+        assert_eq!(
+            select_strategy(&test.repo, &config).unwrap(),
+            BranchRelationStrategy::DefaultRoot
+        );
+
+        config.branch_detection_strategy = BranchRelationStrategy::Manual;
+        assert_eq!(
+            select_strategy(&test.repo, &config).unwrap(),
+            BranchRelationStrategy::Manual
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Module for caching detected branch trees.
+//!
+//! This module stores the result of branch relationship detection in
+//! `.git/gitflow/tree-cache.json`, keyed by a hash of every local branch tip and the detection
+//! settings, so repeated runs on an unchanged repository can skip the detection entirely. The
+//! `gitflow` directory is renamed by `GITFLOW_APP_NAME`.
+//!
+//! # Details
+//! Detailed documentation and example usage are provided to simplify future maintenance.
+
+use crate::configuration::Config;
+use crate::configuration::settings::app_name;
+use crate::error::Result;
+use crate::git::branch::{BranchRelationStrategy, get_branch_tree};
+use git2::{BranchType, Repository};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+
+/// On-disk representation of a cached branch tree
+#[derive(Debug, Serialize, Deserialize)]
+struct TreeCache {
+    /// Hash of the branch tips and detection settings the tree was computed from.
+    key: String,
+    /// The cached branch tree.
+    tree: HashMap<String, Vec<String>>,
+}
+
+/// Get the path of the branch tree cache file inside the repository's Git directory.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `PathBuf` - The cache file path.
+fn cache_path(repo: &Repository) -> PathBuf {
+    repo.path().join(app_name()).join("tree-cache.json")
+}
+
+/// Compute the cache key for the current branch tips and detection settings.
+///
+/// # Arguments
+///
+/// * `repo`     - A reference to the Git repository.
+/// * `strategy` - The branch relation strategy in use.
+/// * `config`   - Configuration whose detection settings affect the tree.
+///
+/// # Returns
+///
+/// * `Result<String>` - A hex-encoded hash identifying the inputs of detection.
+fn cache_key(
+    repo: &Repository,
+    strategy: BranchRelationStrategy,
+    config: &Config,
+) -> Result<String> {
+    let mut tips = Vec::new();
+    for branch_result in repo.branches(Some(BranchType::Local))? {
+        let (branch, _) = branch_result?;
+        let name = branch.name()?.unwrap_or("").to_string();
+        let oid = branch.get().target().map(|oid| oid.to_string());
+        tips.push((name, oid));
+    }
+    tips.sort();
+
+    let mut hasher = DefaultHasher::new();
+    tips.hash(&mut hasher);
+    format!("{:?}", strategy).hash(&mut hasher);
+    config.default_base_branch.hash(&mut hasher);
+    config.history_first_parent.hash(&mut hasher);
+    config.include_branches.hash(&mut hasher);
+    config.ignore_branches.hash(&mut hasher);
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
+/// Build the branch tree, reusing the cached tree when no branch tip has moved.
+///
+/// Manual relationships are read straight from the configuration and are never cached.
+///
+/// # Arguments
+///
+/// * `repo`      - A reference to the Git repository.
+/// * `strategy`  - The branch relation strategy to use.
+/// * `config`    - Reference to the configuration settings.
+/// * `use_cache` - Whether to read and update the cache.
+///
+/// # Returns
+///
+/// * `Result<HashMap<String, Vec<String>>>` - A mapping from parent branch names to their child branches.
+///
+/// # Examples
+/// ```rust
+/// // let tree = get_branch_tree_cached(&repo, strategy, &config, true)?;
+/// ```
+pub fn get_branch_tree_cached(
+    repo: &Repository,
+    strategy: BranchRelationStrategy,
+    config: &Config,
+    use_cache: bool,
+) -> Result<HashMap<String, Vec<String>>> {
+    if !use_cache || strategy == BranchRelationStrategy::Manual {
+        return get_branch_tree(repo, strategy, config);
+    }
+
+    let key = cache_key(repo, strategy, config)?;
+    let path = cache_path(repo);
+
+    if let Ok(json) = fs::read_to_string(&path)
+        && let Ok(cache) = serde_json::from_str::<TreeCache>(&json)
+        && cache.key == key
+    {
+        debug!("Using cached branch tree ({})", key);
+        return Ok(cache.tree);
+    }
+
+    let tree = get_branch_tree(repo, strategy, config)?;
+    let cache = TreeCache { key, tree };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(&cache)?)?;
+    debug!("Updated branch tree cache at {}", path.display());
+    Ok(cache.tree)
+}
@@ -0,0 +1,567 @@
+//! Module for Git commit operations.
+//!
+//! This module provides functionality to stage working tree changes according to a staging
+//! mode and record them as a new commit on the current branch, or fold them into the last one.
+//!
+//! # Details
+//! Detailed documentation and example usage are provided to simplify future maintenance.
+
+use crate::error::{GitFlowError, Result};
+use crate::git::lock::with_index_lock;
+use crate::git::status::get_repo_status;
+use git2::{IndexAddOption, Oid, Repository, Status};
+use glob::Pattern;
+use log::{debug, info};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Controls which working tree changes are staged before committing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StageMode {
+    /// Stage every change, including untracked files
+    All,
+    /// Stage changes to tracked files only, skipping untracked files
+    TrackedOnly,
+    /// Stage only the given paths, including every change below a directory
+    Paths(Vec<String>),
+    /// Stage nothing and commit the index as it is
+    Staged,
+}
+
+/// Stage changes into the index according to the staging mode.
+///
+/// Paths matching one of the `exclude` globs are never staged and stay modified in the
+/// working tree. Changes that are already in the index are left alone.
+///
+/// # Arguments
+///
+/// * `repo`    - A reference to the Git repository.
+/// * `stage`   - Which changes to stage.
+/// * `exclude` - Glob patterns (e.g. `*.local.json`) of paths to leave unstaged.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if a pattern is invalid or the index cannot be
+///   updated.
+///
+/// # Examples
+/// ```rust
+/// // stage_changes(&repo, &StageMode::TrackedOnly, &["config/local.toml".to_string()])?;
+/// ```
+pub fn stage_changes(repo: &Repository, stage: &StageMode, exclude: &[String]) -> Result<()> {
+    let exclude = exclude_patterns(exclude)?;
+    let is_excluded = |path: &str| {
+        let excluded = exclude.iter().any(|pattern| pattern.matches(path));
+        if excluded {
+            debug!("Skipping excluded path {}", path);
+        }
+        excluded
+    };
+
+    let mut index = repo.index()?;
+
+    match stage {
+        // The index is committed as it is.
+        StageMode::Staged => return Ok(()),
+        StageMode::All | StageMode::TrackedOnly => {
+            for entry in get_repo_status(repo, true, None)? {
+                if *stage == StageMode::TrackedOnly && entry.status.is_wt_new() {
+                    debug!("Skipping untracked file {}", entry.path);
+                    continue;
+                }
+                if is_excluded(&entry.path) {
+                    continue;
+                }
+                if entry.status.is_wt_deleted() {
+                    index.remove_path(Path::new(&entry.path))?;
+                } else if entry.status.is_wt_new()
+                    || entry.status.is_wt_modified()
+                    || entry.status.is_wt_renamed()
+                    || entry.status.is_wt_typechange()
+                {
+                    index.add_path(Path::new(&entry.path))?;
+                }
+            }
+        }
+        StageMode::Paths(paths) => {
+            // Paths are pathspecs, so a directory stages every change below it like
+            // `git add <dir>`, and files deleted from the working tree leave the index.
+            let mut skip_excluded =
+                |path: &Path, _: &[u8]| i32::from(is_excluded(&path.to_string_lossy()));
+            let pathspecs = paths.iter().map(String::as_str);
+            index.add_all(
+                pathspecs.clone(),
+                IndexAddOption::DEFAULT,
+                Some(&mut skip_excluded),
+            )?;
+            index.update_all(pathspecs, Some(&mut skip_excluded))?;
+        }
+    }
+
+    index.write()?;
+    Ok(())
+}
+
+/// Check whether staging every change would leave anything to commit.
+///
+/// Changes already in the index always count; working tree changes count unless their path
+/// matches one of the `exclude` globs, as [`stage_changes`] would leave them unstaged.
+///
+/// # Arguments
+///
+/// * `repo`    - A reference to the Git repository.
+/// * `exclude` - Glob patterns of paths never staged.
+///
+/// # Returns
+///
+/// * `Result<bool>` - True if a commit of all changes would not be empty.
+///
+/// # Examples
+/// ```rust
+/// // if has_changes_to_commit(&repo, &config.commit_exclude)? { ... }
+/// ```
+pub fn has_changes_to_commit(repo: &Repository, exclude: &[String]) -> Result<bool> {
+    let exclude = exclude_patterns(exclude)?;
+    let staged = Status::INDEX_NEW
+        | Status::INDEX_MODIFIED
+        | Status::INDEX_DELETED
+        | Status::INDEX_RENAMED
+        | Status::INDEX_TYPECHANGE;
+    Ok(get_repo_status(repo, true, None)?.iter().any(|entry| {
+        entry.status.intersects(staged)
+            || !exclude.iter().any(|pattern| pattern.matches(&entry.path))
+    }))
+}
+
+/// Parse the exclude globs used when staging.
+///
+/// # Arguments
+///
+/// * `exclude` - Glob patterns of paths never staged.
+///
+/// # Returns
+///
+/// * `Result<Vec<Pattern>>` - The parsed patterns, or a configuration error for an invalid one.
+fn exclude_patterns(exclude: &[String]) -> Result<Vec<Pattern>> {
+    exclude
+        .iter()
+        .map(|p| {
+            Pattern::new(p).map_err(|e| {
+                GitFlowError::Config(format!("Invalid exclude pattern '{}': {}", p, e))
+            })
+        })
+        .collect()
+}
+
+/// Stage changes and commit them on the current branch.
+///
+/// # Arguments
+///
+/// * `repo`     - A reference to the Git repository.
+/// * `message`  - The commit message.
+/// * `stage`    - Which changes to stage before committing.
+/// * `trailers` - Trailer lines appended to the message (see [`append_trailers`]).
+/// * `exclude`  - Glob patterns of paths never staged (see [`stage_changes`]).
+///
+/// # Returns
+///
+/// * `Result<Oid>` - The id of the new commit, or an error if there is nothing to commit.
+///
+/// # Examples
+/// ```rust
+/// // let oid = commit_changes(&repo, "Fix typo", &StageMode::All, &[], &[])?;
+/// ```
+pub fn commit_changes(
+    repo: &Repository,
+    message: &str,
+    stage: &StageMode,
+    trailers: &[String],
+    exclude: &[String],
+) -> Result<Oid> {
+    with_index_lock(repo, || {
+        commit_changes_locked(repo, message, stage, trailers, exclude)
+    })
+}
+
+/// The body of [`commit_changes`], run by it under [`with_index_lock`].
+fn commit_changes_locked(
+    repo: &Repository,
+    message: &str,
+    stage: &StageMode,
+    trailers: &[String],
+    exclude: &[String],
+) -> Result<Oid> {
+    stage_changes(repo, stage, exclude)?;
+
+    let mut index = repo.index()?;
+    let tree_id = index.write_tree()?;
+    let tree = repo.find_tree(tree_id)?;
+
+    // An unborn branch has no parent commit yet.
+    let parent = match repo.head() {
+        Ok(head) => Some(head.peel_to_commit()?),
+        Err(_) => None,
+    };
+    // On an unborn branch there is no tree to compare with; an empty index has nothing to commit.
+    let unchanged = match &parent {
+        Some(parent) => parent.tree_id() == tree_id,
+        None => index.is_empty(),
+    };
+    if unchanged {
+        let reason = if *stage == StageMode::Staged {
+            "No staged changes to commit"
+        } else {
+            "Nothing to commit"
+        };
+        return Err(GitFlowError::Aborted(reason.to_string()));
+    }
+
+    let sig = repo.signature()?;
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    let message = append_trailers(message, trailers);
+    let oid = repo.commit(Some("HEAD"), &sig, &sig, &message, &tree, &parents)?;
+
+    info!("Created commit {}", oid);
+    Ok(oid)
+}
+
+/// Stage changes and fold them into the last commit on the current branch.
+///
+/// The last commit is replaced by one with the new tree, keeping its author and, unless a new
+/// message is given, its message. The committer is updated as with `git commit --amend`.
+///
+/// # Arguments
+///
+/// * `repo`     - A reference to the Git repository.
+/// * `message`  - A new commit message, or None to keep the last commit's message.
+/// * `stage`    - Which changes to stage before amending.
+/// * `trailers` - Trailer lines appended to the message (see [`append_trailers`]).
+/// * `exclude`  - Glob patterns of paths never staged (see [`stage_changes`]).
+///
+/// # Returns
+///
+/// * `Result<Oid>` - The id of the amended commit, or an error if the branch has no commit yet
+///   or neither the tree nor the message would change.
+///
+/// # Examples
+/// ```rust
+/// // let oid = amend_commit(&repo, None, &StageMode::All, &[], &[])?;
+/// ```
+pub fn amend_commit(
+    repo: &Repository,
+    message: Option<&str>,
+    stage: &StageMode,
+    trailers: &[String],
+    exclude: &[String],
+) -> Result<Oid> {
+    with_index_lock(repo, || {
+        amend_commit_locked(repo, message, stage, trailers, exclude)
+    })
+}
+
+/// The body of [`amend_commit`], run by it under [`with_index_lock`].
+fn amend_commit_locked(
+    repo: &Repository,
+    message: Option<&str>,
+    stage: &StageMode,
+    trailers: &[String],
+    exclude: &[String],
+) -> Result<Oid> {
+    let last = match repo.head() {
+        Ok(head) => head.peel_to_commit()?,
+        Err(_) => {
+            return Err(GitFlowError::Aborted(
+                "Nothing to amend: the current branch has no commits yet".to_string(),
+            ));
+        }
+    };
+
+    stage_changes(repo, stage, exclude)?;
+
+    let mut index = repo.index()?;
+    let tree_id = index.write_tree()?;
+    let tree = repo.find_tree(tree_id)?;
+
+    let message = append_trailers(
+        message.unwrap_or_else(|| last.message().unwrap_or("")),
+        trailers,
+    );
+    if last.tree_id() == tree_id && last.message().unwrap_or("").trim_end() == message {
+        return Err(GitFlowError::Aborted(
+            "Nothing to amend: no changes and the message is unchanged".to_string(),
+        ));
+    }
+
+    let sig = repo.signature()?;
+    let oid = last.amend(
+        Some("HEAD"),
+        None,
+        Some(&sig),
+        None,
+        Some(&message),
+        Some(&tree),
+    )?;
+
+    info!("Amended commit {} as {}", last.id(), oid);
+    Ok(oid)
+}
+
+/// Ask for a commit message in the user's editor, as `git commit` does without `-m`.
+///
+/// The editor is taken from `GIT_EDITOR`, `core.editor`, `VISUAL` or `EDITOR`, falling back
+/// to `vi`. The message is prefilled with the file named by `commit.template`, if configured.
+/// Lines starting with `#` are stripped from the result.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `Result<String>` - The edited message, or an error if the editor fails or the message is
+///   empty or still the unedited template.
+///
+/// # Examples
+/// ```rust
+/// // let message = edit_commit_message(&repo)?;
+/// ```
+pub fn edit_commit_message(repo: &Repository) -> Result<String> {
+    let config = repo.config()?;
+    let template = match config.get_path("commit.template") {
+        Ok(path) => Some(fs::read_to_string(&path).map_err(|e| {
+            GitFlowError::Config(format!(
+                "Could not read commit template {}: {}",
+                path.display(),
+                e
+            ))
+        })?),
+        Err(_) => None,
+    };
+
+    let path = repo.path().join("COMMIT_EDITMSG");
+    fs::write(
+        &path,
+        format!(
+            "{}\n# Please enter the commit message for your changes. Lines starting\n\
+             # with '#' will be ignored, and an empty message aborts the commit.\n",
+            template.as_deref().unwrap_or("").trim_end()
+        ),
+    )?;
+
+    let editor = env::var("GIT_EDITOR")
+        .ok()
+        .or_else(|| config.get_string("core.editor").ok())
+        .or_else(|| env::var("VISUAL").ok())
+        .or_else(|| env::var("EDITOR").ok())
+        .filter(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    debug!("Editing commit message with {}", editor);
+
+    // Run through the shell like git does, so editors configured with arguments work.
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$@\"", editor))
+        .arg(&editor)
+        .arg(&path)
+        .status()?;
+    if !status.success() {
+        return Err(GitFlowError::Aborted(format!(
+            "Editor '{}' exited with {}",
+            editor, status
+        )));
+    }
+
+    let message = strip_comments(&fs::read_to_string(&path)?);
+    if message.is_empty() {
+        return Err(GitFlowError::Aborted(
+            "Aborting commit due to empty commit message".to_string(),
+        ));
+    }
+    if template.is_some_and(|template| strip_comments(&template) == message) {
+        return Err(GitFlowError::Aborted(
+            "Aborting commit; the commit template was not edited".to_string(),
+        ));
+    }
+    Ok(message)
+}
+
+/// Remove comment lines and surrounding blank lines from an edited commit message.
+///
+/// # Arguments
+///
+/// * `message` - The message as written by the editor.
+///
+/// # Returns
+///
+/// * `String` - The message without lines starting with `#`, trimmed.
+///
+/// # Examples
+/// ```rust
+/// // let message = strip_comments("Fix typo\n# comment\n");
+/// ```
+pub fn strip_comments(message: &str) -> String {
+    message
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Append trailer lines to a commit message.
+///
+/// Trailers already present in the message, or repeated in `trailers`, are only added once.
+/// They are separated from the body by a blank line, unless the message already ends in a
+/// trailer block, in which case they extend that block.
+///
+/// # Arguments
+///
+/// * `message`  - The commit message.
+/// * `trailers` - Trailer lines such as `Signed-off-by: Jane <jane@example.com>`.
+///
+/// # Returns
+///
+/// * `String` - The message with the trailers appended.
+///
+/// # Examples
+/// ```rust
+/// // let message = append_trailers("Fix typo", &["Change-Id: I123".to_string()]);
+/// ```
+pub fn append_trailers(message: &str, trailers: &[String]) -> String {
+    let message = message.trim_end();
+    let existing: Vec<&str> = message.lines().map(str::trim).collect();
+
+    let mut new_trailers: Vec<&str> = Vec::new();
+    for trailer in trailers.iter().map(|t| t.trim()) {
+        if !trailer.is_empty() && !existing.contains(&trailer) && !new_trailers.contains(&trailer) {
+            new_trailers.push(trailer);
+        }
+    }
+    if new_trailers.is_empty() {
+        return message.to_string();
+    }
+
+    // The last paragraph is a trailer block if every line looks like "Key: value".
+    let last_paragraph = message.rsplit("\n\n").next().unwrap_or("");
+    let ends_with_trailers = message.contains("\n\n")
+        && last_paragraph.lines().all(|line| {
+            line.split_once(": ")
+                .is_some_and(|(key, _)| !key.is_empty() && !key.contains(' '))
+        });
+
+    let separator = if ends_with_trailers { "\n" } else { "\n\n" };
+    format!("{}{}{}", message, separator, new_trailers.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::testing::TestRepo;
+
+    /// List the files a commit changed compared to its first parent.
+    fn changed_files(repo: &Repository, oid: Oid) -> Vec<String> {
+        let commit = repo.find_commit(oid).unwrap();
+        let parent_tree = commit.parent(0).ok().map(|parent| parent.tree().unwrap());
+        let diff = repo
+            .diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree().unwrap()), None)
+            .unwrap();
+        let mut files: Vec<String> = diff
+            .deltas()
+            .map(|delta| {
+                let file = delta.new_file().path().or(delta.old_file().path());
+                file.unwrap().to_string_lossy().into_owned()
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
+    /// A repository with a committed file that is now modified, and an untracked file.
+    fn repo_with_changes() -> TestRepo {
+        let test = TestRepo::new();
+        test.commit_file("tracked.txt", "one\n", "initial");
+        test.write("tracked.txt", "two\n");
+        test.write("untracked.txt", "new\n");
+        test
+    }
+
+    #[test]
+    fn all_stages_tracked_and_untracked_files() {
+        let test = repo_with_changes();
+        let oid = commit_changes(&test.repo, "all", &StageMode::All, &[], &[]).unwrap();
+        assert_eq!(
+            changed_files(&test.repo, oid),
+            ["tracked.txt", "untracked.txt"]
+        );
+    }
+
+    #[test]
+    fn all_leaves_excluded_paths_unstaged() {
+        let test = repo_with_changes();
+        test.write("settings.local", "secret\n");
+        let exclude = ["*.local".to_string()];
+        let oid = commit_changes(&test.repo, "all", &StageMode::All, &[], &exclude).unwrap();
+        assert_eq!(
+            changed_files(&test.repo, oid),
+            ["tracked.txt", "untracked.txt"]
+        );
+    }
+
+    #[test]
+    fn tracked_only_skips_untracked_files() {
+        let test = repo_with_changes();
+        let oid = commit_changes(&test.repo, "tracked", &StageMode::TrackedOnly, &[], &[]).unwrap();
+        assert_eq!(changed_files(&test.repo, oid), ["tracked.txt"]);
+        let untracked = get_repo_status(&test.repo, true, None).unwrap();
+        assert!(untracked.iter().all(|entry| entry.status.is_wt_new()));
+    }
+
+    #[test]
+    fn staged_commits_only_the_index() {
+        let test = repo_with_changes();
+        let mut index = test.repo.index().unwrap();
+        index.add_path(Path::new("untracked.txt")).unwrap();
+        index.write().unwrap();
+
+        let oid = commit_changes(&test.repo, "staged", &StageMode::Staged, &[], &[]).unwrap();
+        assert_eq!(changed_files(&test.repo, oid), ["untracked.txt"]);
+    }
+
+    #[test]
+    fn staged_refuses_an_empty_index() {
+        let test = TestRepo::new();
+        test.write("scratch.txt", "draft\n");
+        let result = commit_changes(&test.repo, "staged", &StageMode::Staged, &[], &[]);
+        assert!(matches!(result, Err(GitFlowError::Aborted(_))));
+    }
+
+    #[test]
+    fn paths_stages_only_the_given_files() {
+        let test = repo_with_changes();
+        let stage = StageMode::Paths(vec!["untracked.txt".to_string()]);
+        let oid = commit_changes(&test.repo, "paths", &stage, &[], &[]).unwrap();
+        assert_eq!(changed_files(&test.repo, oid), ["untracked.txt"]);
+    }
+
+    #[test]
+    fn paths_stages_every_change_below_a_directory() {
+        let test = TestRepo::new();
+        test.commit_file("docs/old.txt", "old\n", "initial");
+        test.commit_file("docs/kept.txt", "kept\n", "more docs");
+        test.write("docs/kept.txt", "changed\n");
+        test.write("docs/guide/new.txt", "new\n");
+        test.write("outside.txt", "outside\n");
+        fs::remove_file(test.repo.workdir().unwrap().join("docs/old.txt")).unwrap();
+
+        let stage = StageMode::Paths(vec!["docs".to_string()]);
+        let oid = commit_changes(&test.repo, "docs", &stage, &[], &[]).unwrap();
+        assert_eq!(
+            changed_files(&test.repo, oid),
+            ["docs/guide/new.txt", "docs/kept.txt", "docs/old.txt"]
+        );
+    }
+}
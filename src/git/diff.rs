@@ -0,0 +1,51 @@
+//! Module for Git diff operations.
+//!
+//! This module provides functionality to compute the changes a branch introduces over a base
+//! branch, diffing from their merge base so that later commits on the base are not included.
+//!
+//! # Details
+//! Detailed documentation and example usage are provided to simplify future maintenance.
+
+use crate::error::{GitFlowError, Result};
+use git2::{BranchType, Diff, Repository};
+use log::debug;
+
+/// Compute the diff a branch introduces over a base branch.
+///
+/// This is the equivalent of `git diff <base>...<branch>`.
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `branch` - The branch whose changes are shown.
+/// * `base`   - The branch the changes are compared against.
+///
+/// # Returns
+///
+/// * `Result<Diff>` - The diff from the merge base tree to the branch tree.
+///
+/// # Examples
+/// ```rust
+/// // let diff = diff_against_base(&repo, "feature", "main")?;
+/// ```
+pub fn diff_against_base<'repo>(
+    repo: &'repo Repository,
+    branch: &str,
+    base: &str,
+) -> Result<Diff<'repo>> {
+    let tip = |name: &str| -> Result<git2::Commit<'repo>> {
+        let branch = repo
+            .find_branch(name, BranchType::Local)
+            .map_err(|_| GitFlowError::BranchNotFound(name.to_string()))?;
+        Ok(branch.get().peel_to_commit()?)
+    };
+    let branch_commit = tip(branch)?;
+    let base_commit = tip(base)?;
+
+    let merge_base = repo.merge_base(base_commit.id(), branch_commit.id())?;
+    debug!("Merge base of {} and {} is {}", branch, base, merge_base);
+
+    let old_tree = repo.find_commit(merge_base)?.tree()?;
+    let new_tree = branch_commit.tree()?;
+    Ok(repo.diff_tree_to_tree(Some(&old_tree), Some(&new_tree), None)?)
+}
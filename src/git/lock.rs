@@ -0,0 +1,115 @@
+//! Module for handling index lock contention.
+//!
+//! This module waits for `.git/index.lock` to be released before operations that write the
+//! index, so that a concurrent git process (an editor integration, a background fetch) does not
+//! make gitflow fail with a confusing lock error. Lock failures git2 reports once such an
+//! operation is under way are explained as well.
+//!
+//! # Details
+//! Detailed documentation and example usage are provided to simplify future maintenance.
+
+use crate::error::{GitFlowError, Result};
+use git2::{ErrorCode, Repository};
+use log::{debug, warn};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// Number of times the lock is checked before giving up.
+const LOCK_ATTEMPTS: u32 = 5;
+
+/// Delay between two checks of the lock.
+const LOCK_RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// Whether to fail immediately instead of waiting for the index lock.
+static NO_WAIT: AtomicBool = AtomicBool::new(false);
+
+/// Fail immediately when the index is locked instead of retrying
+///
+/// # Arguments
+/// * `enabled` - True to disable waiting for the index lock.
+///
+/// # Examples
+/// ```rust
+/// // set_no_wait(cli.no_wait);
+/// ```
+pub fn set_no_wait(enabled: bool) {
+    NO_WAIT.store(enabled, Ordering::Relaxed);
+}
+
+/// Wait until no other process holds the index lock.
+///
+/// The lock is checked a few times with a short delay in between, unless `--no-wait` is in
+/// effect, in which case a held lock fails straight away.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok once the index is unlocked, or an error if it stays locked.
+///
+/// # Examples
+/// ```rust
+/// // wait_for_index_lock(&repo)?;
+/// ```
+pub fn wait_for_index_lock(repo: &Repository) -> Result<()> {
+    let lock_path = repo.path().join("index.lock");
+    let attempts = if NO_WAIT.load(Ordering::Relaxed) {
+        1
+    } else {
+        LOCK_ATTEMPTS
+    };
+
+    for attempt in 1..=attempts {
+        if !lock_path.exists() {
+            return Ok(());
+        }
+        if attempt < attempts {
+            debug!(
+                "{} exists, retrying ({}/{})",
+                lock_path.display(),
+                attempt,
+                attempts
+            );
+            thread::sleep(LOCK_RETRY_DELAY);
+        }
+    }
+
+    warn!("{} is still present", lock_path.display());
+    Err(GitFlowError::Aborted(format!(
+        "The index is locked by another git process ({} exists). Wait for it to finish, or remove the file if no git process is running.",
+        lock_path.display()
+    )))
+}
+
+/// Run an operation that writes the index once no other process holds the index lock.
+///
+/// The lock is waited for as in [`wait_for_index_lock`]. A lock error git2 reports while the
+/// operation runs, meaning another git process took the lock in the meantime, is converted to
+/// an Aborted error explaining that. It is not retried, since the operation may be half done.
+///
+/// # Arguments
+///
+/// * `repo`      - A reference to the Git repository.
+/// * `operation` - The operation to run.
+///
+/// # Returns
+///
+/// * `Result<T>` - The result of the operation, or an error if the index stays locked.
+///
+/// # Examples
+/// ```rust
+/// // with_index_lock(&repo, || merge(&repo, "main", "feature", &config))?;
+/// ```
+pub fn with_index_lock<T>(repo: &Repository, operation: impl FnOnce() -> Result<T>) -> Result<T> {
+    wait_for_index_lock(repo)?;
+    operation().map_err(|err| match err {
+        GitFlowError::Git(e) if e.code() == ErrorCode::Locked => GitFlowError::Aborted(format!(
+            "Another git process may be running: {}",
+            e.message()
+        )),
+        other => other,
+    })
+}
@@ -6,11 +6,16 @@
 //! # Details
 //! Detailed documentation and example usage are provided to simplify future maintenance.
 
+use crate::configuration::Config;
 use crate::error::{GitFlowError, Result};
 use crate::git::branch::{checkout_branch, get_current_branch};
+use crate::git::lock::with_index_lock;
+use crate::git::rerere;
 use crate::git::status::get_repo_status;
-use git2::{ErrorCode, MergeOptions, Repository};
-use log::{info, warn};
+use git2::build::CheckoutBuilder;
+use git2::{Commit, ErrorCode, MergeOptions, Oid, Repository, Signature, Sort};
+use log::{debug, info, warn};
+use std::env;
 
 /// Merge one branch into another with proper conflict handling.
 ///
@@ -19,6 +24,7 @@ use log::{info, warn};
 /// * `repo` - A reference to the Git repository.
 /// * `from` - The source branch name.
 /// * `to` - The target branch name.
+/// * `config` - Configuration providing the merge options.
 ///
 /// # Returns
 ///
@@ -28,13 +34,18 @@ use log::{info, warn};
 /// ```rust
 /// // Example: Merge branch "feature" into "main"
 /// // let repo = Repository::open(".")?;
-/// // merge_branch(&repo, "feature", "main")?;
+/// // merge_branch(&repo, "feature", "main", &config)?;
 /// ```
-pub fn merge_branch(repo: &Repository, from: &str, to: &str) -> Result<()> {
+pub fn merge_branch(repo: &Repository, from: &str, to: &str, config: &Config) -> Result<()> {
+    with_index_lock(repo, || merge_branch_locked(repo, from, to, config))
+}
+
+/// The body of [`merge_branch`], run by it under [`with_index_lock`].
+fn merge_branch_locked(repo: &Repository, from: &str, to: &str, config: &Config) -> Result<()> {
     info!("Merging {} into {}", from, to);
 
     // Ensure there are no uncommitted changes in the repository.
-    let status = get_repo_status(repo, false)?;
+    let status = get_repo_status(repo, false, None)?;
     if !status.is_empty() {
         return Err(GitFlowError::Aborted(
             "There are uncommitted changes. Please commit or stash them first.".to_string(),
@@ -44,6 +55,11 @@ pub fn merge_branch(repo: &Repository, from: &str, to: &str) -> Result<()> {
     // Save the current branch to restore later.
     let original_branch = get_current_branch(repo)?;
 
+    // A target that is not checked out can be merged without touching the working directory.
+    if config.in_memory_merge && original_branch != to {
+        return merge_in_memory(repo, from, to, config);
+    }
+
     // Checkout the target branch.
     checkout_branch(repo, to)?;
 
@@ -52,7 +68,7 @@ pub fn merge_branch(repo: &Repository, from: &str, to: &str) -> Result<()> {
     let annotated_commit = repo.reference_to_annotated_commit(&reference)?;
 
     // Prepare merge options.
-    let mut merge_options = MergeOptions::new();
+    let mut merge_options = merge_options(config);
     merge_options.fail_on_conflict(false);
 
     // Perform merge analysis.
@@ -71,7 +87,8 @@ pub fn merge_branch(repo: &Repository, from: &str, to: &str) -> Result<()> {
         repo.set_head(target_ref.name().unwrap())?;
     } else {
         // Normal merge process.
-        let sig = repo.signature()?;
+        let merged_commit = repo.find_commit(annotated_commit.id())?;
+        let (author, committer) = merge_signatures(repo, config, &[&merged_commit])?;
         let result = repo.merge(&[&annotated_commit], Some(&mut merge_options), None);
 
         if let Err(e) = result {
@@ -90,9 +107,15 @@ pub fn merge_branch(repo: &Repository, from: &str, to: &str) -> Result<()> {
             }
         }
 
-        // Verify if conflicts exist in the merge index.
+        // Verify if conflicts exist in the merge index, replaying recorded resolutions first.
         let mut index = repo.index()?;
+        if index.has_conflicts() && config.use_rerere && rerere::replay(repo)? {
+            index.read(true)?;
+        }
         if index.has_conflicts() {
+            if config.use_rerere {
+                rerere::clear(repo);
+            }
             repo.cleanup_state()?;
             if original_branch != to {
                 checkout_branch(repo, &original_branch)?;
@@ -107,15 +130,34 @@ pub fn merge_branch(repo: &Repository, from: &str, to: &str) -> Result<()> {
         let tree_id = index.write_tree()?;
         let tree = repo.find_tree(tree_id)?;
         let head_commit = repo.head()?.peel_to_commit()?;
-        let merged_commit = repo.find_commit(annotated_commit.id())?;
-        repo.commit(
+        let message = merge_message(
+            repo,
+            format!("Merge branch '{}' into '{}'", from, to),
+            head_commit.id(),
+            &[merged_commit.id()],
+            config,
+        )?;
+        let merge_oid = repo.commit(
             Some("HEAD"),
-            &sig,
-            &sig,
-            &format!("Merge branch '{}' into '{}'", from, to),
+            &author,
+            &committer,
+            &message,
             &tree,
             &[&head_commit, &merged_commit],
         )?;
+
+        // Make sure HEAD really moved to the merge commit before dropping the merge state.
+        let new_head = repo.head()?.peel_to_commit()?.id();
+        if new_head != merge_oid || new_head == head_commit.id() {
+            return Err(GitFlowError::Unknown(format!(
+                "Merge commit {} of {} into {} was not recorded: HEAD is at {} (was {})",
+                merge_oid,
+                from,
+                to,
+                new_head,
+                head_commit.id()
+            )));
+        }
         repo.cleanup_state()?;
     }
 
@@ -127,3 +169,577 @@ pub fn merge_branch(repo: &Repository, from: &str, to: &str) -> Result<()> {
     info!("Successfully merged {} into {}", from, to);
     Ok(())
 }
+
+/// Merge one branch into another in memory, without checking out the target.
+///
+/// The merged tree is built from the two commits' trees and committed directly onto the
+/// target ref, so neither the working directory, the index nor HEAD are touched. The target
+/// must not be the checked-out branch, or the working directory would fall out of date.
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `from`   - The source branch name.
+/// * `to`     - The target branch name.
+/// * `config` - Configuration providing the merge options.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an Aborted error if the merge conflicts.
+///
+/// # Examples
+/// ```rust
+/// // merge_in_memory(&repo, "main", "feature", &config)?;
+/// ```
+fn merge_in_memory(repo: &Repository, from: &str, to: &str, config: &Config) -> Result<()> {
+    let target_ref = format!("refs/heads/{}", to);
+    let target_commit = repo.find_reference(&target_ref)?.peel_to_commit()?;
+    let source = repo
+        .find_reference(&format!("refs/heads/{}", from))?
+        .peel_to_commit()?;
+
+    if source.id() == target_commit.id()
+        || repo.graph_descendant_of(target_commit.id(), source.id())?
+    {
+        info!("Already up-to-date");
+    } else if repo.graph_descendant_of(source.id(), target_commit.id())? {
+        info!("Performing fast-forward merge");
+        repo.find_reference(&target_ref)?
+            .set_target(source.id(), "Fast-forward")?;
+    } else {
+        let mut index =
+            repo.merge_commits(&target_commit, &source, Some(&merge_options(config)))?;
+        if index.has_conflicts() {
+            warn!("Merge conflicts detected");
+            return Err(GitFlowError::Aborted(format!(
+                "Merge conflicts detected between {} and {}. Please resolve manually.",
+                from, to
+            )));
+        }
+
+        let tree = repo.find_tree(index.write_tree_to(repo)?)?;
+        let (author, committer) = merge_signatures(repo, config, &[&source])?;
+        let message = merge_message(
+            repo,
+            format!("Merge branch '{}' into '{}'", from, to),
+            target_commit.id(),
+            &[source.id()],
+            config,
+        )?;
+        repo.commit(
+            Some(&target_ref),
+            &author,
+            &committer,
+            &message,
+            &tree,
+            &[&target_commit, &source],
+        )?;
+    }
+
+    info!("Successfully merged {} into {}", from, to);
+    Ok(())
+}
+
+/// Merge several branches into a target with a single merge commit.
+///
+/// The source trees are merged into the target one after another in memory, so the working
+/// directory is only touched once the combined commit exists. Sources the target already
+/// contains are skipped; if any merge conflicts, nothing is committed.
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `froms`  - The source branch names.
+/// * `to`     - The target branch name.
+/// * `config` - Configuration providing the merge options.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if any merge conflicts.
+///
+/// # Examples
+/// ```rust
+/// // merge_branches(&repo, &["feature-a", "feature-b"], "release", &config)?;
+/// ```
+pub fn merge_branches(repo: &Repository, froms: &[&str], to: &str, config: &Config) -> Result<()> {
+    with_index_lock(repo, || merge_branches_locked(repo, froms, to, config))
+}
+
+/// The body of [`merge_branches`], run by it under [`with_index_lock`].
+fn merge_branches_locked(
+    repo: &Repository,
+    froms: &[&str],
+    to: &str,
+    config: &Config,
+) -> Result<()> {
+    info!("Merging {} into {}", froms.join(", "), to);
+
+    // Ensure there are no uncommitted changes in the repository.
+    let status = get_repo_status(repo, false, None)?;
+    if !status.is_empty() {
+        return Err(GitFlowError::Aborted(
+            "There are uncommitted changes. Please commit or stash them first.".to_string(),
+        ));
+    }
+
+    let target_ref = format!("refs/heads/{}", to);
+    let target_commit = repo.find_reference(&target_ref)?.peel_to_commit()?;
+    let mut tree = target_commit.tree()?;
+    let mut sources = Vec::new();
+    let mut source_names = Vec::new();
+
+    for from in froms {
+        let source = repo
+            .find_reference(&format!("refs/heads/{}", from))?
+            .peel_to_commit()?;
+        if source.id() == target_commit.id()
+            || repo.graph_descendant_of(target_commit.id(), source.id())?
+        {
+            info!("{} is already contained in {}", from, to);
+            continue;
+        }
+
+        let base = repo.merge_base(target_commit.id(), source.id())?;
+        let ancestor_tree = repo.find_commit(base)?.tree()?;
+        let mut index = repo.merge_trees(
+            &ancestor_tree,
+            &tree,
+            &source.tree()?,
+            Some(&merge_options(config)),
+        )?;
+        if index.has_conflicts() {
+            warn!("Merge conflicts detected");
+            return Err(GitFlowError::Aborted(format!(
+                "Merge conflicts detected between {} and {}. Please resolve manually.",
+                from, to
+            )));
+        }
+
+        tree = repo.find_tree(index.write_tree_to(repo)?)?;
+        sources.push(source);
+        source_names.push(format!("'{}'", from));
+    }
+
+    if sources.is_empty() {
+        info!("Already up-to-date");
+        return Ok(());
+    }
+
+    let source_commits: Vec<&Commit> = sources.iter().collect();
+    let (author, committer) = merge_signatures(repo, config, &source_commits)?;
+    let mut parents = vec![&target_commit];
+    parents.extend(sources.iter());
+    let source_ids: Vec<Oid> = sources.iter().map(|source| source.id()).collect();
+    let message = merge_message(
+        repo,
+        format!("Merge branches {} into '{}'", source_names.join(", "), to),
+        target_commit.id(),
+        &source_ids,
+        config,
+    )?;
+    repo.commit(
+        Some(&target_ref),
+        &author,
+        &committer,
+        &message,
+        &tree,
+        &parents,
+    )?;
+
+    // Bring the working directory in line if the target is checked out.
+    if get_current_branch(repo).ok().as_deref() == Some(to) {
+        repo.checkout_head(Some(CheckoutBuilder::new().force()))?;
+    }
+
+    info!(
+        "Successfully merged {} into {}",
+        source_names.join(", "),
+        to
+    );
+    Ok(())
+}
+
+/// Check whether a target branch already contains every commit of a source branch.
+///
+/// This only inspects the commit graph, so unlike [`merge_branch`] it needs no checkout.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+/// * `from` - The source branch name.
+/// * `to`   - The target branch name.
+///
+/// # Returns
+///
+/// * `Result<bool>` - True if merging `from` into `to` would be a no-op.
+///
+/// # Examples
+/// ```rust
+/// // if is_up_to_date(&repo, "main", "feature")? { ... }
+/// ```
+pub fn is_up_to_date(repo: &Repository, from: &str, to: &str) -> Result<bool> {
+    let from_oid = repo
+        .find_reference(&format!("refs/heads/{}", from))?
+        .peel_to_commit()?
+        .id();
+    let to_oid = repo
+        .find_reference(&format!("refs/heads/{}", to))?
+        .peel_to_commit()?
+        .id();
+    Ok(from_oid == to_oid || repo.graph_descendant_of(to_oid, from_oid)?)
+}
+
+/// Preview the files that would conflict when merging one branch into another.
+///
+/// The merge is performed in memory, so neither the working directory, the index nor HEAD
+/// are touched and no checkout is required.
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `from`   - The source branch name.
+/// * `to`     - The target branch name.
+/// * `config` - Configuration providing the merge options.
+///
+/// # Returns
+///
+/// * `Result<Vec<String>>` - The conflicting paths, empty if the merge would be clean.
+///
+/// # Examples
+/// ```rust
+/// // let conflicts = preview_conflicts(&repo, "main", "feature", &config)?;
+/// ```
+pub fn preview_conflicts(
+    repo: &Repository,
+    from: &str,
+    to: &str,
+    config: &Config,
+) -> Result<Vec<String>> {
+    let from_commit = repo
+        .find_reference(&format!("refs/heads/{}", from))?
+        .peel_to_commit()?;
+    let to_commit = repo
+        .find_reference(&format!("refs/heads/{}", to))?
+        .peel_to_commit()?;
+
+    let index = repo.merge_commits(&to_commit, &from_commit, Some(&merge_options(config)))?;
+    let mut paths = Vec::new();
+    for conflict in index.conflicts()? {
+        let conflict = conflict?;
+        // Prefer "our" side's path, falling back to whichever side still has the file.
+        let entry = conflict.our.or(conflict.their).or(conflict.ancestor);
+        if let Some(entry) = entry {
+            paths.push(String::from_utf8_lossy(&entry.path).into_owned());
+        }
+    }
+
+    debug!("Conflicts merging {} into {}: {:?}", from, to, paths);
+    Ok(paths)
+}
+
+/// Build a merge commit message, optionally listing the commits being merged.
+///
+/// When `merge_include_commit_list` is enabled, the summary line is followed by a bulleted
+/// list of the summaries of every commit reachable from the sources but not from the target,
+/// oldest first.
+///
+/// # Arguments
+///
+/// * `repo`    - A reference to the Git repository.
+/// * `summary` - The first line of the message.
+/// * `target`  - The commit being merged into.
+/// * `sources` - The commits being merged.
+/// * `config`  - Configuration deciding whether the commit list is included.
+///
+/// # Returns
+///
+/// * `Result<String>` - The full commit message.
+///
+/// # Examples
+/// ```rust
+/// // let message = merge_message(&repo, summary, head.id(), &[source.id()], &config)?;
+/// ```
+fn merge_message(
+    repo: &Repository,
+    summary: String,
+    target: Oid,
+    sources: &[Oid],
+    config: &Config,
+) -> Result<String> {
+    if !config.merge_include_commit_list {
+        return Ok(summary);
+    }
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)?;
+    for source in sources {
+        revwalk.push(*source)?;
+    }
+    revwalk.hide(target)?;
+
+    let mut lines = Vec::new();
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        lines.push(format!("* {}", commit.summary().unwrap_or("")));
+    }
+    if lines.is_empty() {
+        return Ok(summary);
+    }
+    Ok(format!("{}\n\n{}", summary, lines.join("\n")))
+}
+
+/// Build the merge options configured for merges and merge previews.
+///
+/// When `merge_rename_threshold` is set, rename detection is enabled with that similarity
+/// threshold (0-100); otherwise git2's defaults are used. Rename detection compares every
+/// added file against every deleted one, so low thresholds on merges touching many files can
+/// be noticeably slower and may pair up unrelated files.
+///
+/// The flags in `merge_options` are applied on top; `find_renames` there overrides the
+/// threshold's implied rename detection. Whitespace flags make whitespace-only differences
+/// merge cleanly instead of conflicting, and `minimal`/`patience` change how hunks are
+/// matched, so all of them affect conflict detection.
+///
+/// # Arguments
+///
+/// * `config` - Configuration providing the merge settings.
+///
+/// # Returns
+///
+/// * `MergeOptions` - The merge options to use.
+///
+/// # Examples
+/// ```rust
+/// // let opts = merge_options(&config);
+/// ```
+pub fn merge_options(config: &Config) -> MergeOptions {
+    let mut options = MergeOptions::new();
+    if let Some(threshold) = config.merge_rename_threshold {
+        options.find_renames(true);
+        options.rename_threshold(threshold);
+    }
+
+    let flags = &config.merge_options;
+    if let Some(enabled) = flags.find_renames {
+        options.find_renames(enabled);
+    }
+    if let Some(enabled) = flags.ignore_whitespace {
+        options.ignore_whitespace(enabled);
+    }
+    if let Some(enabled) = flags.ignore_whitespace_change {
+        options.ignore_whitespace_change(enabled);
+    }
+    if let Some(enabled) = flags.ignore_whitespace_eol {
+        options.ignore_whitespace_eol(enabled);
+    }
+    if let Some(enabled) = flags.minimal {
+        options.minimal(enabled);
+    }
+    if let Some(enabled) = flags.patience {
+        options.patience(enabled);
+    }
+    options
+}
+
+/// Resolve the author and committer signatures for a merge commit.
+///
+/// The author always comes from the repository configuration. The committer defaults to the
+/// same signature, but can be overridden with `GIT_COMMITTER_NAME` and `GIT_COMMITTER_EMAIL`
+/// so that e.g. a CI bot shows up as committer while the human remains the author.
+///
+/// With `preserve_author_date` set, the author date is taken from the newest of the merged
+/// commits instead of the current time; the committer date stays the current time.
+///
+/// # Arguments
+///
+/// * `repo`    - A reference to the Git repository.
+/// * `config`  - Configuration deciding whether the author date is preserved.
+/// * `sources` - The commits being merged.
+///
+/// # Returns
+///
+/// * `Result<(Signature, Signature)>` - The author and committer signatures.
+///
+/// # Examples
+/// ```rust
+/// // let (author, committer) = merge_signatures(&repo, &config, &[&merged_commit])?;
+/// ```
+pub fn merge_signatures(
+    repo: &Repository,
+    config: &Config,
+    sources: &[&Commit],
+) -> Result<(Signature<'static>, Signature<'static>)> {
+    let committer = match (
+        env::var("GIT_COMMITTER_NAME"),
+        env::var("GIT_COMMITTER_EMAIL"),
+    ) {
+        (Ok(name), Ok(email)) => Some(Signature::now(&name, &email)?),
+        _ => None,
+    };
+    signatures_with_committer(repo, config, sources, committer)
+}
+
+/// Resolve the author and committer signatures for a merge commit with a given committer.
+///
+/// # Arguments
+///
+/// * `repo`      - A reference to the Git repository.
+/// * `config`    - Configuration deciding whether the author date is preserved.
+/// * `sources`   - The commits being merged.
+/// * `committer` - The committer to use, or None to commit as the author.
+///
+/// # Returns
+///
+/// * `Result<(Signature, Signature)>` - The author and committer signatures.
+fn signatures_with_committer(
+    repo: &Repository,
+    config: &Config,
+    sources: &[&Commit],
+    committer: Option<Signature<'static>>,
+) -> Result<(Signature<'static>, Signature<'static>)> {
+    let mut author = repo.signature()?;
+    let committer = committer.unwrap_or_else(|| author.clone());
+
+    if config.preserve_author_date
+        && let Some(when) = sources
+            .iter()
+            .map(|commit| commit.author().when())
+            .max_by_key(|when| when.seconds())
+    {
+        let (name, email) = (author.name().unwrap_or(""), author.email().unwrap_or(""));
+        author = Signature::new(name, email, &when)?;
+    }
+    Ok((author, committer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::testing::TestRepo;
+
+    /// Build `main` and `feature` branches that both changed a different file since they forked.
+    fn diverged_repo() -> TestRepo {
+        let test = TestRepo::new();
+        test.commit_file("base.txt", "base\n", "base");
+        test.branch("feature");
+        test.commit_file("main.txt", "main\n", "main work");
+        test.checkout("feature");
+        test.commit_file("feature.txt", "feature\n", "feature work");
+        test.checkout("main");
+        test
+    }
+
+    #[test]
+    fn normal_merge_moves_the_target_to_the_merge_commit() {
+        let test = diverged_repo();
+        let (main_before, feature) = (test.tip("main"), test.tip("feature"));
+
+        merge_branch(&test.repo, "feature", "main", &Config::default()).unwrap();
+
+        let merge = test.repo.find_commit(test.tip("main")).unwrap();
+        assert_eq!(
+            merge.parent_ids().collect::<Vec<_>>(),
+            [main_before, feature]
+        );
+        let head = test.repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.id(), merge.id());
+    }
+
+    #[test]
+    fn normal_merge_into_another_branch_returns_to_the_original() {
+        let test = diverged_repo();
+        let (main, feature_before) = (test.tip("main"), test.tip("feature"));
+
+        merge_branch(&test.repo, "main", "feature", &Config::default()).unwrap();
+
+        let merge = test.repo.find_commit(test.tip("feature")).unwrap();
+        assert_eq!(
+            merge.parent_ids().collect::<Vec<_>>(),
+            [feature_before, main]
+        );
+        assert_eq!(get_current_branch(&test.repo).unwrap(), "main");
+        assert_eq!(test.tip("main"), main);
+    }
+
+    #[test]
+    fn whitespace_only_divergence_conflicts_unless_whitespace_is_ignored() {
+        let test = TestRepo::new();
+        test.commit_file("lib.rs", "fn main() {\n    call();\n}\n", "base");
+        test.branch("feature");
+        test.commit_file("lib.rs", "fn main() {\n\tcall();\n}\n", "re-indent");
+        test.checkout("feature");
+        test.commit_file("lib.rs", "fn main() {\n    call(1);\n}\n", "change call");
+        test.checkout("main");
+
+        let mut config = Config::default();
+        let conflicts = preview_conflicts(&test.repo, "feature", "main", &config).unwrap();
+        assert_eq!(conflicts, ["lib.rs"]);
+
+        config.merge_options.ignore_whitespace = Some(true);
+        let conflicts = preview_conflicts(&test.repo, "feature", "main", &config).unwrap();
+        assert!(conflicts.is_empty());
+        merge_branch(&test.repo, "feature", "main", &config).unwrap();
+        let merge = test.repo.find_commit(test.tip("main")).unwrap();
+        assert_eq!(merge.parent_count(), 2);
+    }
+
+    #[test]
+    fn merge_keeps_the_merged_author_date_when_preserving() {
+        let test = TestRepo::new();
+        test.commit_file("base.txt", "base\n", "base");
+        test.branch("feature");
+        test.commit_file("main.txt", "main\n", "main work");
+        test.checkout("feature");
+        test.commit_file_at("feature.txt", "feature\n", "feature work", 1_000_000_000);
+        test.checkout("main");
+
+        let mut config = Config::default();
+        config.preserve_author_date = true;
+        merge_branch(&test.repo, "feature", "main", &config).unwrap();
+
+        let merge = test.repo.find_commit(test.tip("main")).unwrap();
+        assert_eq!(merge.parent_count(), 2);
+        assert_eq!(merge.author().when().seconds(), 1_000_000_000);
+        assert_ne!(merge.committer().when().seconds(), 1_000_000_000);
+    }
+
+    #[test]
+    fn merge_commit_can_have_a_different_committer() {
+        let test = diverged_repo();
+        let repo = &test.repo;
+        let feature = repo.find_commit(test.tip("feature")).unwrap();
+        let bot = Signature::now("CI Bot", "ci@example.com").unwrap();
+
+        let (author, committer) =
+            signatures_with_committer(repo, &Config::default(), &[&feature], Some(bot)).unwrap();
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        let tree = head.tree().unwrap();
+        let oid = repo
+            .commit(
+                None,
+                &author,
+                &committer,
+                "merge",
+                &tree,
+                &[&head, &feature],
+            )
+            .unwrap();
+
+        let commit = repo.find_commit(oid).unwrap();
+        assert_eq!(commit.author().name(), Some("Test Author"));
+        assert_eq!(commit.committer().name(), Some("CI Bot"));
+        assert_ne!(commit.author().email(), commit.committer().email());
+    }
+
+    #[test]
+    fn committer_defaults_to_the_author() {
+        let test = diverged_repo();
+        let feature = test.repo.find_commit(test.tip("feature")).unwrap();
+
+        let (author, committer) =
+            signatures_with_committer(&test.repo, &Config::default(), &[&feature], None).unwrap();
+
+        assert_eq!(author.name(), committer.name());
+        assert_eq!(author.email(), committer.email());
+    }
+}
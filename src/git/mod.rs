@@ -1,7 +1,25 @@
 pub mod branch;
+pub mod cache;
+pub mod commit;
+pub mod diff;
+pub mod lock;
 pub mod merge;
+pub mod plan;
+pub mod progress;
+pub mod rebase;
+pub mod remote;
+pub mod report;
+pub mod rerere;
+pub mod shared;
 pub mod status;
+pub mod tag;
+#[cfg(test)]
+pub mod testing;
 
 pub use branch::*;
-pub use merge::merge_branch;
+pub use cache::get_branch_tree_cached;
+pub use commit::{StageMode, amend_commit, commit_changes, edit_commit_message};
+pub use lock::set_no_wait;
+pub use merge::{is_up_to_date, merge_branch, merge_branches, preview_conflicts};
+pub use remote::{configure_network, fetch_all, push_branch, remote_divergence};
 //pub use status::get_repo_status;
@@ -0,0 +1,224 @@
+//! Module for cascade merge plans.
+//!
+//! This module computes the ordered list of merges a cascade would perform and stores it as
+//! JSON, together with the branch tips it was computed from, so that a reviewed plan can later
+//! be executed verbatim once it is confirmed the branches have not moved.
+//!
+//! # Details
+//! Detailed documentation and example usage are provided to simplify future maintenance.
+
+use crate::error::{GitFlowError, Result};
+use crate::git::branch::find_root_branches;
+use crate::git::merge::is_up_to_date;
+use git2::{BranchType, Repository};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+/// The kind of merge a planned operation is expected to perform
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MergeKind {
+    /// The child already contains the parent
+    UpToDate,
+    /// The child can be moved forward to the parent
+    FastForward,
+    /// A merge commit is required
+    Merge,
+}
+
+/// A single merge of a parent branch into a child branch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedMerge {
+    /// The branch being merged.
+    pub parent: String,
+    /// The branch receiving the merge.
+    pub child: String,
+    /// The expected kind of merge, as of when the plan was generated.
+    pub merge_type: MergeKind,
+}
+
+/// An ordered cascade plan and the branch tips it was computed from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CascadePlan {
+    /// Tip commit of every branch involved in the plan.
+    pub tips: BTreeMap<String, String>,
+    /// The merges to perform, in order.
+    pub operations: Vec<PlannedMerge>,
+}
+
+/// Compute the cascade plan for a branch tree.
+///
+/// Operations are ordered the same way the cascade processes them: depth-first from each
+/// root branch, merging a parent into each of its children before descending.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `branch_tree` - Mapping from parent branches to child branches.
+///
+/// # Returns
+///
+/// * `Result<CascadePlan>` - The plan, or an error if a branch cannot be resolved.
+///
+/// # Examples
+/// ```rust
+/// // let plan = build_plan(&repo, &branch_tree)?;
+/// ```
+pub fn build_plan(
+    repo: &Repository,
+    branch_tree: &HashMap<String, Vec<String>>,
+) -> Result<CascadePlan> {
+    let mut plan = CascadePlan {
+        tips: BTreeMap::new(),
+        operations: Vec::new(),
+    };
+    let mut processed = HashSet::new();
+    for root in find_root_branches(branch_tree) {
+        plan_recursive(repo, &root, branch_tree, &mut processed, &mut plan)?;
+    }
+    Ok(plan)
+}
+
+/// Append the merges below a branch to the plan, depth-first.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `branch`      - The branch whose children are planned.
+/// * `branch_tree` - Mapping from parent branches to child branches.
+/// * `processed`   - Branches already visited.
+/// * `plan`        - The plan being built.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success.
+fn plan_recursive(
+    repo: &Repository,
+    branch: &str,
+    branch_tree: &HashMap<String, Vec<String>>,
+    processed: &mut HashSet<String>,
+    plan: &mut CascadePlan,
+) -> Result<()> {
+    if !processed.insert(branch.to_string()) {
+        return Ok(());
+    }
+    record_tip(repo, branch, plan)?;
+
+    if let Some(children) = branch_tree.get(branch) {
+        for child in children {
+            record_tip(repo, child, plan)?;
+            // A parent moved by an earlier operation is no longer contained in the child.
+            let parent_moves = plan
+                .operations
+                .iter()
+                .any(|op| op.child == branch && op.merge_type != MergeKind::UpToDate);
+            let merge_type = if is_up_to_date(repo, branch, child)? && !parent_moves {
+                MergeKind::UpToDate
+            } else if is_up_to_date(repo, child, branch)? {
+                MergeKind::FastForward
+            } else {
+                MergeKind::Merge
+            };
+            plan.operations.push(PlannedMerge {
+                parent: branch.to_string(),
+                child: child.clone(),
+                merge_type,
+            });
+            plan_recursive(repo, child, branch_tree, processed, plan)?;
+        }
+    }
+    Ok(())
+}
+
+/// Record the current tip of a branch in the plan.
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `branch` - The branch name.
+/// * `plan`   - The plan being built.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the branch does not exist.
+fn record_tip(repo: &Repository, branch: &str, plan: &mut CascadePlan) -> Result<()> {
+    if !plan.tips.contains_key(branch) {
+        plan.tips
+            .insert(branch.to_string(), branch_tip(repo, branch)?);
+    }
+    Ok(())
+}
+
+/// Get the tip commit id of a local branch.
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `branch` - The branch name.
+///
+/// # Returns
+///
+/// * `Result<String>` - The tip commit id.
+pub fn branch_tip(repo: &Repository, branch: &str) -> Result<String> {
+    let commit = repo
+        .find_branch(branch, BranchType::Local)
+        .map_err(|_| GitFlowError::BranchNotFound(branch.to_string()))?
+        .get()
+        .peel_to_commit()?;
+    Ok(commit.id().to_string())
+}
+
+/// Write a plan to a JSON file.
+///
+/// # Arguments
+///
+/// * `plan` - The plan to write.
+/// * `path` - The destination file.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the file cannot be written.
+///
+/// # Examples
+/// ```rust
+/// // write_plan(&plan, Path::new("plan.json"))?;
+/// ```
+pub fn write_plan(plan: &CascadePlan, path: &Path) -> Result<()> {
+    fs::write(path, serde_json::to_string_pretty(plan)?)?;
+    Ok(())
+}
+
+/// Read a plan from a JSON file and check that none of its branches have moved.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+/// * `path` - The plan file.
+///
+/// # Returns
+///
+/// * `Result<CascadePlan>` - The plan, or an error if it cannot be read or is stale.
+///
+/// # Examples
+/// ```rust
+/// // let plan = read_plan(&repo, Path::new("plan.json"))?;
+/// ```
+pub fn read_plan(repo: &Repository, path: &Path) -> Result<CascadePlan> {
+    let plan: CascadePlan = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+    let moved: Vec<&str> = plan
+        .tips
+        .iter()
+        .filter(|(branch, tip)| branch_tip(repo, branch).ok().as_ref() != Some(*tip))
+        .map(|(branch, _)| branch.as_str())
+        .collect();
+    if !moved.is_empty() {
+        return Err(GitFlowError::Aborted(format!(
+            "The plan is stale: {} changed since it was generated",
+            moved.join(", ")
+        )));
+    }
+    Ok(plan)
+}
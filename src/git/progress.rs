@@ -0,0 +1,221 @@
+//! Module for cascade progress.
+//!
+//! This module records which merges of a running cascade have completed in
+//! `.git/gitflow/cascade-state.json`, so a cascade interrupted by a conflict or an error can be
+//! resumed after the problem is fixed instead of starting over. The `gitflow` directory is
+//! renamed by `GITFLOW_APP_NAME`.
+//!
+//! # Details
+//! Detailed documentation and example usage are provided to simplify future maintenance.
+
+use crate::configuration::settings::app_name;
+use crate::error::{GitFlowError, Result};
+use crate::git::plan::branch_tip;
+use git2::Repository;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// A merge of a parent into a child that has completed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletedMerge {
+    /// The branch that was merged.
+    pub parent: String,
+    /// The branch that received the merge.
+    pub child: String,
+    /// Tip commit of the parent when it was merged.
+    pub parent_tip: String,
+}
+
+/// Progress of a cascade: the branch tree being cascaded and the merges done so far
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CascadeState {
+    /// The branch tree being cascaded.
+    pub tree: HashMap<String, Vec<String>>,
+    /// Completed merges, including children that were already up to date.
+    pub completed: Vec<CompletedMerge>,
+}
+
+impl CascadeState {
+    /// Start tracking a cascade over a branch tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `tree` - Mapping from parent branches to child branches.
+    ///
+    /// # Returns
+    ///
+    /// * `CascadeState` - A state with no merges completed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let state = CascadeState::new(branch_tree.clone());
+    /// ```
+    pub fn new(tree: HashMap<String, Vec<String>>) -> Self {
+        CascadeState {
+            tree,
+            completed: Vec::new(),
+        }
+    }
+
+    /// Check whether the merge of a parent into a child has completed.
+    ///
+    /// A merge only counts as completed while the parent is still at the tip it had when it
+    /// was merged; if the parent moved since, e.g. because a merge into it was finished by
+    /// hand, the merge has to be repeated.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent`     - The branch being merged.
+    /// * `child`      - The branch receiving the merge.
+    /// * `parent_tip` - The current tip commit of the parent.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - True if the merge is recorded as completed for this parent tip.
+    pub fn is_completed(&self, parent: &str, child: &str, parent_tip: &str) -> bool {
+        self.completed
+            .iter()
+            .any(|m| m.parent == parent && m.child == child && m.parent_tip == parent_tip)
+    }
+
+    /// Record the merge of a parent into a child as completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent`     - The branch being merged.
+    /// * `child`      - The branch receiving the merge.
+    /// * `parent_tip` - The tip commit of the parent that was merged.
+    pub fn complete(&mut self, parent: &str, child: &str, parent_tip: &str) {
+        self.completed
+            .retain(|m| !(m.parent == parent && m.child == child));
+        self.completed.push(CompletedMerge {
+            parent: parent.to_string(),
+            child: child.to_string(),
+            parent_tip: parent_tip.to_string(),
+        });
+    }
+
+    /// List the merges of the tree that have not completed for the current branch tips.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - A reference to the Git repository.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<(&str, &str)>>` - The pending (parent, child) pairs, or an error if a
+    ///   parent branch cannot be resolved.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // for (parent, child) in state.pending(&repo)? { ... }
+    /// ```
+    pub fn pending(&self, repo: &Repository) -> Result<Vec<(&str, &str)>> {
+        let mut pending = Vec::new();
+        for (parent, children) in &self.tree {
+            let tip = branch_tip(repo, parent)?;
+            for child in children {
+                if !self.is_completed(parent, child, &tip) {
+                    pending.push((parent.as_str(), child.as_str()));
+                }
+            }
+        }
+        Ok(pending)
+    }
+}
+
+/// Get the path of the cascade state file inside the repository's Git directory.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `PathBuf` - The state file path.
+fn state_path(repo: &Repository) -> PathBuf {
+    repo.path().join(app_name()).join("cascade-state.json")
+}
+
+/// Save the progress of a cascade.
+///
+/// # Arguments
+///
+/// * `repo`  - A reference to the Git repository.
+/// * `state` - The progress to save.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the file cannot be written.
+///
+/// # Examples
+/// ```rust
+/// // save_state(&repo, &state)?;
+/// ```
+pub fn save_state(repo: &Repository, state: &CascadeState) -> Result<()> {
+    let path = state_path(repo);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(state)?)?;
+    debug!("Saved cascade progress to {}", path.display());
+    Ok(())
+}
+
+/// Load the progress of an interrupted cascade.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `Result<Option<CascadeState>>` - The saved progress, None if no cascade was interrupted, or
+///   an error if the file cannot be read.
+///
+/// # Examples
+/// ```rust
+/// // if let Some(state) = load_state(&repo)? { ... }
+/// ```
+pub fn load_state(repo: &Repository) -> Result<Option<CascadeState>> {
+    let path = state_path(repo);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let json = fs::read_to_string(&path)?;
+    let state = serde_json::from_str(&json).map_err(|e| {
+        GitFlowError::Config(format!(
+            "Invalid cascade state in {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+    Ok(Some(state))
+}
+
+/// Discard the progress of an interrupted cascade.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `Result<bool>` - True if saved progress was removed, false if there was none.
+///
+/// # Examples
+/// ```rust
+/// // clear_state(&repo)?;
+/// ```
+pub fn clear_state(repo: &Repository) -> Result<bool> {
+    let path = state_path(repo);
+    if !path.exists() {
+        return Ok(false);
+    }
+    fs::remove_file(&path)?;
+    debug!("Removed cascade progress at {}", path.display());
+    Ok(true)
+}
@@ -0,0 +1,133 @@
+//! Module for Git rebase operations.
+//!
+//! This module provides functionality to replay a branch's commits onto a new base, aborting
+//! the rebase and restoring the original branch if conflicts are encountered.
+//!
+//! # Details
+//! Detailed documentation and example usage are provided to simplify future maintenance.
+
+use crate::error::{GitFlowError, Result};
+use crate::git::branch::{checkout_branch, get_current_branch};
+use crate::git::lock::with_index_lock;
+use crate::git::status::get_repo_status;
+use git2::{ErrorCode, Repository};
+use log::{debug, info, warn};
+
+/// Rebase the commits of `branch` that are not on `upstream` onto `onto`.
+///
+/// This is the equivalent of `git rebase --onto <onto> <upstream> <branch>`. `upstream` and
+/// `onto` may also name remote-tracking branches such as `origin/feature`.
+///
+/// # Arguments
+///
+/// * `repo`     - A reference to the Git repository.
+/// * `branch`   - The branch to rebase.
+/// * `upstream` - The branch the commits were originally based on.
+/// * `onto`     - The branch to replay the commits onto.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the rebase fails or conflicts.
+///
+/// # Examples
+/// ```rust
+/// // Move "feature" from "develop" onto "main"
+/// // rebase_onto(&repo, "feature", "develop", "main")?;
+/// ```
+pub fn rebase_onto(repo: &Repository, branch: &str, upstream: &str, onto: &str) -> Result<()> {
+    with_index_lock(repo, || rebase_onto_locked(repo, branch, upstream, onto))
+}
+
+/// The body of [`rebase_onto`], run by it under [`with_index_lock`].
+fn rebase_onto_locked(repo: &Repository, branch: &str, upstream: &str, onto: &str) -> Result<()> {
+    info!("Rebasing {} from {} onto {}", branch, upstream, onto);
+
+    // Ensure there are no uncommitted changes in the repository.
+    let status = get_repo_status(repo, false, None)?;
+    if !status.is_empty() {
+        return Err(GitFlowError::Aborted(
+            "There are uncommitted changes. Please commit or stash them first.".to_string(),
+        ));
+    }
+
+    // Save the current branch to restore later.
+    let original_branch = get_current_branch(repo)?;
+
+    let annotated = |name: &str| -> Result<git2::AnnotatedCommit<'_>> {
+        let reference = repo
+            .find_reference(&format!("refs/heads/{}", name))
+            .or_else(|_| repo.find_reference(&format!("refs/remotes/{}", name)))?;
+        Ok(repo.reference_to_annotated_commit(&reference)?)
+    };
+    let branch_commit = annotated(branch)?;
+    let upstream_commit = annotated(upstream)?;
+    let onto_commit = annotated(onto)?;
+
+    let sig = repo.signature()?;
+    let mut rebase = repo.rebase(
+        Some(&branch_commit),
+        Some(&upstream_commit),
+        Some(&onto_commit),
+        None,
+    )?;
+
+    while let Some(operation) = rebase.next() {
+        let operation = operation?;
+        if repo.index()?.has_conflicts() {
+            warn!("Rebase conflicts detected");
+            rebase.abort()?;
+            if original_branch != branch {
+                checkout_branch(repo, &original_branch)?;
+            }
+            return Err(GitFlowError::Aborted(format!(
+                "Rebase conflicts detected while moving {} onto {}. Please resolve manually.",
+                branch, onto
+            )));
+        }
+        // Without an explicit author each commit keeps its original author and author date.
+        match rebase.commit(None, &sig, None) {
+            Ok(_) => {}
+            // The change is already present on the new base; skip it.
+            Err(e) if e.code() == ErrorCode::Applied => {
+                debug!("Skipping already applied commit {}", operation.id());
+            }
+            Err(e) => {
+                rebase.abort()?;
+                return Err(GitFlowError::Git(e));
+            }
+        }
+    }
+    rebase.finish(Some(&sig))?;
+
+    // Return to the original branch if necessary.
+    if original_branch != branch {
+        checkout_branch(repo, &original_branch)?;
+    }
+
+    info!("Successfully rebased {} onto {}", branch, onto);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::testing::TestRepo;
+
+    #[test]
+    fn rebased_commits_keep_their_author_date() {
+        let test = TestRepo::new();
+        test.commit_file("base.txt", "base\n", "base");
+        test.branch("feature");
+        test.commit_file("main.txt", "main\n", "main work");
+        test.checkout("feature");
+        test.commit_file_at("feature.txt", "feature\n", "feature work", 1_000_000_000);
+        test.checkout("main");
+
+        rebase_onto(&test.repo, "feature", "main", "main").unwrap();
+
+        let rebased = test.repo.find_commit(test.tip("feature")).unwrap();
+        assert_eq!(rebased.parent_id(0).unwrap(), test.tip("main"));
+        assert_eq!(rebased.author().when().seconds(), 1_000_000_000);
+        assert_ne!(rebased.committer().when().seconds(), 1_000_000_000);
+    }
+}
@@ -0,0 +1,706 @@
+//! Module for Git remote operations.
+//!
+//! This module provides functionality to push local branches to their remotes, resolving the
+//! remote to use from the branch's upstream configuration and authenticating through the SSH
+//! agent or the configured Git credential helper. Network operations time out and are retried
+//! on transient transport errors according to the configured network settings.
+//!
+//! # Details
+//! Detailed documentation and example usage are provided to simplify future maintenance.
+
+use crate::error::{GitFlowError, Result};
+use git2::build::CheckoutBuilder;
+use git2::{
+    BranchType, Cred, CredentialType, Direction, ErrorClass, ErrorCode, FetchOptions, PushOptions,
+    RemoteCallbacks, Repository,
+};
+use log::{debug, info, warn};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// The remote used when a branch has no upstream configured.
+pub const DEFAULT_REMOTE: &str = "origin";
+
+/// Number of times a network operation is retried after a transient error.
+static NETWORK_RETRIES: AtomicU32 = AtomicU32::new(0);
+
+/// Set the timeout and retry count used by fetch and push.
+///
+/// The timeout applies both to establishing the connection and to each read or write on it,
+/// so a server that stops responding mid-transfer fails as well. It must be called before any
+/// other thread is started, because libgit2 keeps the timeouts in unsynchronized globals.
+///
+/// # Arguments
+///
+/// * `timeout_secs` - Seconds to wait for the server before failing.
+/// * `retries`      - How often to retry after a transient transport error.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if libgit2 rejects the timeout.
+///
+/// # Examples
+/// ```rust
+/// // configure_network(config.git_network_timeout_secs(), config.git_network_retries())?;
+/// ```
+pub fn configure_network(timeout_secs: u64, retries: u32) -> Result<()> {
+    let timeout_ms = i32::try_from(timeout_secs.saturating_mul(1000)).unwrap_or(i32::MAX);
+    // SAFETY: called once at startup, before any thread that uses libgit2 is spawned.
+    unsafe {
+        git2::opts::set_server_connect_timeout_in_milliseconds(timeout_ms)?;
+        git2::opts::set_server_timeout_in_milliseconds(timeout_ms)?;
+    }
+    NETWORK_RETRIES.store(retries, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Check whether a git2 error is a transport failure that may succeed when retried.
+///
+/// # Arguments
+///
+/// * `err` - The error returned by a network operation.
+///
+/// # Returns
+///
+/// * `bool` - True for timeouts and network, HTTP or SSH errors other than authentication
+///   failures.
+fn is_transient(err: &git2::Error) -> bool {
+    if err.code() == git2::ErrorCode::Timeout {
+        return true;
+    }
+    err.code() != git2::ErrorCode::Auth
+        && matches!(
+            err.class(),
+            git2::ErrorClass::Net | git2::ErrorClass::Http | git2::ErrorClass::Ssh
+        )
+}
+
+/// Run a network operation, retrying it after transient transport errors.
+///
+/// Retries wait one second longer each time. Once the retries are exhausted the error is
+/// reported as a `Timeout` or `Network` error; other errors are returned immediately.
+///
+/// # Arguments
+///
+/// * `what`      - Description of the operation for log and error messages.
+/// * `operation` - The operation to run; it is called again for each retry.
+///
+/// # Returns
+///
+/// * `Result<T>` - The operation's result, or an error once it fails for good.
+///
+/// # Examples
+/// ```rust
+/// // with_retries("fetch origin", || remote.fetch(&[] as &[&str], None, None))?;
+/// ```
+pub fn with_retries<T>(
+    what: &str,
+    mut operation: impl FnMut() -> std::result::Result<T, git2::Error>,
+) -> Result<T> {
+    let retries = NETWORK_RETRIES.load(Ordering::Relaxed);
+    let mut attempt = 0;
+    loop {
+        match operation() {
+            Ok(value) => return Ok(value),
+            Err(e) if is_transient(&e) && attempt < retries => {
+                attempt += 1;
+                warn!(
+                    "{} failed ({}), retrying ({}/{})",
+                    what,
+                    e.message(),
+                    attempt,
+                    retries
+                );
+                thread::sleep(Duration::from_secs(attempt as u64));
+            }
+            Err(e) if e.code() == git2::ErrorCode::Timeout => {
+                return Err(GitFlowError::Timeout(format!(
+                    "{} after {} attempt(s): {}",
+                    what,
+                    attempt + 1,
+                    e.message()
+                )));
+            }
+            Err(e) if is_transient(&e) => {
+                return Err(GitFlowError::Network(format!(
+                    "{} failed after {} attempt(s): {}",
+                    what,
+                    attempt + 1,
+                    e.message()
+                )));
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Build remote callbacks that authenticate using the SSH agent or credential helper.
+///
+/// Each kind of credential is offered once; when the server rejects it, the operation fails
+/// with an authentication error instead of being asked for the same credential again. Refs
+/// the server refuses to update during a push are reported as errors too, since libgit2
+/// otherwise treats a push with rejected refs as successful.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository, used to read the credential helper configuration.
+///
+/// # Returns
+///
+/// * `RemoteCallbacks` - Callbacks ready to be used for fetch or push operations.
+///
+/// # Examples
+/// ```rust
+/// // let callbacks = remote_callbacks(&repo);
+/// ```
+pub fn remote_callbacks(repo: &Repository) -> RemoteCallbacks<'_> {
+    let mut callbacks = RemoteCallbacks::new();
+    let mut tried_agent = false;
+    let mut tried_helper = false;
+    callbacks.credentials(move |url, username, allowed| {
+        if allowed.contains(CredentialType::SSH_KEY) && !tried_agent {
+            tried_agent = true;
+            return Cred::ssh_key_from_agent(username.unwrap_or("git"));
+        }
+        if allowed.contains(CredentialType::USER_PASS_PLAINTEXT) && !tried_helper {
+            tried_helper = true;
+            let config = repo.config()?;
+            return Cred::credential_helper(&config, url, username);
+        }
+        if tried_agent || tried_helper {
+            return Err(git2::Error::new(
+                ErrorCode::Auth,
+                ErrorClass::Net,
+                format!("Authentication to {} failed", url),
+            ));
+        }
+        Cred::default()
+    });
+    callbacks.push_update_reference(|refname, status| match status {
+        Some(reason) => Err(git2::Error::new(
+            ErrorCode::User,
+            ErrorClass::Reference,
+            format!("{} was rejected by the remote: {}", refname, reason),
+        )),
+        None => Ok(()),
+    });
+    callbacks
+}
+
+/// Turn a failed push into an error, keeping refs rejected by the remote apart.
+///
+/// # Arguments
+///
+/// * `err` - The error returned by a push run through [`with_retries`].
+///
+/// # Returns
+///
+/// * `GitFlowError` - `PushRejected` for refs the remote refused, the error unchanged otherwise.
+///
+/// # Examples
+/// ```rust
+/// // with_retries("push", || remote.push(&[&refspec], None)).map_err(push_error)?;
+/// ```
+pub fn push_error(err: GitFlowError) -> GitFlowError {
+    match err {
+        GitFlowError::Git(e)
+            if e.code() == ErrorCode::User && e.class() == ErrorClass::Reference =>
+        {
+            GitFlowError::PushRejected(e.message().to_string())
+        }
+        other => other,
+    }
+}
+
+/// Get the name of the remote a branch tracks, if any.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `branch_name` - The local branch name.
+///
+/// # Returns
+///
+/// * `Option<String>` - The upstream remote name, or None if the branch has no upstream.
+///
+/// # Examples
+/// ```rust
+/// // let remote = get_upstream_remote(&repo, "feature");
+/// ```
+pub fn get_upstream_remote(repo: &Repository, branch_name: &str) -> Option<String> {
+    repo.branch_upstream_remote(&format!("refs/heads/{}", branch_name))
+        .ok()
+        .and_then(|buf| buf.as_str().map(String::from))
+}
+
+/// Split a remote URL into its host, owner and repository name.
+///
+/// HTTPS (`https://host/owner/repo.git`), SSH (`ssh://git@host:22/owner/repo.git`) and
+/// scp-like (`git@host:owner/repo.git`) URLs are understood. Nested groups (as on GitLab)
+/// are kept in the owner.
+///
+/// # Arguments
+///
+/// * `url` - The remote URL.
+///
+/// # Returns
+///
+/// * `Option<(String, String, String)>` - The host, owner and repository, or None if the URL
+///   has no host or no owner.
+///
+/// # Examples
+/// ```rust
+/// // let (host, owner, name) = parse_remote_url("git@github.com:acme/widgets.git").unwrap();
+/// ```
+pub fn parse_remote_url(url: &str) -> Option<(String, String, String)> {
+    let (host, path) = match url.split_once("://") {
+        Some((_, rest)) => rest.split_once('/')?,
+        None => url.split_once(':')?,
+    };
+    // Drop the user and port, e.g. "git@github.com:22".
+    let host = host.rsplit('@').next()?.split(':').next()?;
+    let path = path.trim_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let (owner, name) = path.rsplit_once('/')?;
+    if host.is_empty() || owner.is_empty() || name.is_empty() {
+        return None;
+    }
+    Some((host.to_string(), owner.to_string(), name.to_string()))
+}
+
+/// Get the web address of the repository a branch is pushed to.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `branch_name` - The branch whose remote is used (the default remote if it has none).
+///
+/// # Returns
+///
+/// * `Result<String>` - The repository's `https://` address, or a configuration error if the
+///   remote URL cannot be parsed.
+///
+/// # Examples
+/// ```rust
+/// // let url = web_url(&repo, "feature")?; // "https://github.com/acme/widgets"
+/// ```
+pub fn web_url(repo: &Repository, branch_name: &str) -> Result<String> {
+    let remote_name =
+        get_upstream_remote(repo, branch_name).unwrap_or_else(|| DEFAULT_REMOTE.to_string());
+    let remote = repo.find_remote(&remote_name)?;
+    let url = remote.url().unwrap_or("");
+    let (host, owner, name) = parse_remote_url(url).ok_or_else(|| {
+        GitFlowError::Config(format!(
+            "Cannot derive a web address from the URL of {}: '{}'",
+            remote_name, url
+        ))
+    })?;
+    Ok(format!("https://{}/{}/{}", host, owner, name))
+}
+
+/// Check whether a branch has an upstream or a same-named branch on the default remote.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `branch_name` - The local branch name.
+///
+/// # Returns
+///
+/// * `bool` - True if the branch is published on a remote.
+///
+/// # Examples
+/// ```rust
+/// // if has_remote_branch(&repo, "feature") { ... }
+/// ```
+pub fn has_remote_branch(repo: &Repository, branch_name: &str) -> bool {
+    let has_upstream = repo
+        .find_branch(branch_name, BranchType::Local)
+        .and_then(|branch| branch.upstream())
+        .is_ok();
+    has_upstream
+        || repo
+            .find_branch(
+                &format!("{}/{}", DEFAULT_REMOTE, branch_name),
+                BranchType::Remote,
+            )
+            .is_ok()
+}
+
+/// Check whether the remote-tracking branch points at the same commit as the local branch.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `branch_name` - The local branch name.
+/// * `remote_name` - The remote the branch is pushed to.
+///
+/// # Returns
+///
+/// * `bool` - True if both tips are known and identical.
+///
+/// # Examples
+/// ```rust
+/// // if is_up_to_date_with_remote(&repo, "feature", "origin") { ... }
+/// ```
+pub fn is_up_to_date_with_remote(repo: &Repository, branch_name: &str, remote_name: &str) -> bool {
+    let local = repo
+        .find_reference(&format!("refs/heads/{}", branch_name))
+        .ok()
+        .and_then(|reference| reference.target());
+    let remote = repo
+        .find_reference(&format!("refs/remotes/{}/{}", remote_name, branch_name))
+        .ok()
+        .and_then(|reference| reference.target());
+    local.is_some() && local == remote
+}
+
+/// Count the commits a branch and its remote-tracking branch have that the other lacks.
+///
+/// The comparison is against the same-named branch on `remote_name`, which is where
+/// `push_branch` publishes to, rather than the configured upstream: a branch created from
+/// `origin/main` tracks it but is still pushed to its own name. A branch is diverged when
+/// both counts are non-zero, in which case a non-force push would be rejected by the remote.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `remote_name` - The remote the branch is pushed to.
+/// * `branch_name` - The local branch name.
+///
+/// # Returns
+///
+/// * `Result<Option<(usize, usize)>>` - The (ahead, behind) counts, or None if the branch has
+///   no remote-tracking branch on `remote_name`.
+///
+/// # Examples
+/// ```rust
+/// // if let Some((ahead, behind)) = remote_divergence(&repo, "origin", "feature")? { ... }
+/// ```
+pub fn remote_divergence(
+    repo: &Repository,
+    remote_name: &str,
+    branch_name: &str,
+) -> Result<Option<(usize, usize)>> {
+    let local = repo
+        .find_reference(&format!("refs/heads/{}", branch_name))?
+        .target();
+    let remote = repo
+        .find_reference(&format!("refs/remotes/{}/{}", remote_name, branch_name))
+        .ok()
+        .and_then(|reference| reference.target());
+    let (Some(local), Some(remote)) = (local, remote) else {
+        return Ok(None);
+    };
+    Ok(Some(repo.graph_ahead_behind(local, remote)?))
+}
+
+/// Check that a remote branch is still where the last fetch saw it, before force-pushing.
+///
+/// The remote is queried for the current tip of the branch, which must match the local
+/// remote-tracking branch (or be absent if there is none). Otherwise someone else pushed
+/// since the last fetch and a force push would discard their commits.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `remote_name` - The remote the branch is pushed to.
+/// * `branch_name` - The local branch name.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok if the lease holds, or an Aborted error if the remote branch moved.
+///
+/// # Examples
+/// ```rust
+/// // verify_lease(&repo, "origin", "feature")?;
+/// ```
+pub fn verify_lease(repo: &Repository, remote_name: &str, branch_name: &str) -> Result<()> {
+    let expected = repo
+        .find_reference(&format!("refs/remotes/{}/{}", remote_name, branch_name))
+        .ok()
+        .and_then(|reference| reference.target());
+
+    let mut remote = repo.find_remote(remote_name)?;
+    with_retries(&format!("Connecting to {}", remote_name), || {
+        remote
+            .connect_auth(Direction::Push, Some(remote_callbacks(repo)), None)
+            .map(|_| ())
+    })?;
+    let refname = format!("refs/heads/{}", branch_name);
+    let actual = remote
+        .list()?
+        .iter()
+        .find(|head| head.name() == refname)
+        .map(|head| head.oid());
+    remote.disconnect()?;
+
+    if actual != expected {
+        let describe = |oid: Option<git2::Oid>| {
+            oid.map_or_else(|| "no branch".to_string(), |oid| oid.to_string())
+        };
+        return Err(GitFlowError::Aborted(format!(
+            "{} on {} has moved since the last fetch (expected {}, found {}); someone else pushed. Fetch and integrate their changes before pushing again.",
+            branch_name,
+            remote_name,
+            describe(expected),
+            describe(actual)
+        )));
+    }
+    debug!("Lease on {}/{} holds", remote_name, branch_name);
+    Ok(())
+}
+
+/// Push a local branch to its remote and set the upstream if it has none.
+///
+/// The push is skipped when the remote-tracking branch already points at the local tip.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `branch_name` - The local branch to push.
+/// * `force`       - Overwrite the remote branch even if it is not an ancestor of the local tip.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the push fails.
+///
+/// # Examples
+/// ```rust
+/// // push_branch(&repo, "feature", false)?;
+/// ```
+pub fn push_branch(repo: &Repository, branch_name: &str, force: bool) -> Result<()> {
+    let remote_name =
+        get_upstream_remote(repo, branch_name).unwrap_or_else(|| DEFAULT_REMOTE.to_string());
+
+    if is_up_to_date_with_remote(repo, branch_name, &remote_name) {
+        info!("{} is already up to date on {}", branch_name, remote_name);
+        return Ok(());
+    }
+    info!("Pushing {} to {}", branch_name, remote_name);
+
+    let mut remote = repo.find_remote(&remote_name)?;
+    let prefix = if force { "+" } else { "" };
+    let refspec = format!("{0}refs/heads/{1}:refs/heads/{1}", prefix, branch_name);
+
+    with_retries(
+        &format!("Pushing {} to {}", branch_name, remote_name),
+        || {
+            let mut push_options = PushOptions::new();
+            push_options.remote_callbacks(remote_callbacks(repo));
+            remote.push(&[&refspec], Some(&mut push_options))
+        },
+    )
+    .map_err(push_error)?;
+
+    // Track the pushed branch so later pushes know where to go.
+    let mut branch = repo.find_branch(branch_name, BranchType::Local)?;
+    if branch.upstream().is_err() {
+        branch.set_upstream(Some(&format!("{}/{}", remote_name, branch_name)))?;
+        debug!(
+            "Set upstream of {} to {}/{}",
+            branch_name, remote_name, branch_name
+        );
+    }
+
+    info!("Pushed {} to {}", branch_name, remote_name);
+    Ok(())
+}
+
+/// Ensure `refs/remotes/origin/HEAD` points at the remote's default branch.
+///
+/// If the symbolic ref is missing or dangling, the remote is queried for its default branch
+/// and the ref is recreated locally.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `Result<String>` - The name of the remote's default branch (e.g. "main").
+///
+/// # Examples
+/// ```rust
+/// // let default_branch = ensure_origin_head(&repo)?;
+/// ```
+pub fn ensure_origin_head(repo: &Repository) -> Result<String> {
+    let head_ref = format!("refs/remotes/{}/HEAD", DEFAULT_REMOTE);
+    let prefix = format!("refs/remotes/{}/", DEFAULT_REMOTE);
+
+    // Use the existing symbolic ref if it resolves to a remote branch.
+    if let Ok(reference) = repo.find_reference(&head_ref)
+        && let Some(target) = reference.symbolic_target()
+        && repo.find_reference(target).is_ok()
+        && let Some(name) = target.strip_prefix(&prefix)
+    {
+        return Ok(name.to_string());
+    }
+
+    info!("{} is missing or dangling, querying the remote", head_ref);
+    let mut remote = repo.find_remote(DEFAULT_REMOTE)?;
+    with_retries(&format!("Connecting to {}", DEFAULT_REMOTE), || {
+        remote
+            .connect_auth(Direction::Fetch, Some(remote_callbacks(repo)), None)
+            .map(|_| ())
+    })?;
+    let default_ref = remote.default_branch()?;
+    remote.disconnect()?;
+
+    let default_ref = default_ref.as_str().ok_or_else(|| {
+        GitFlowError::Git(git2::Error::from_str(
+            "Invalid UTF-8 in default branch name",
+        ))
+    })?;
+    let name = default_ref
+        .strip_prefix("refs/heads/")
+        .unwrap_or(default_ref)
+        .to_string();
+
+    let target = format!("{}{}", prefix, name);
+    if repo.find_reference(&target).is_err() {
+        return Err(GitFlowError::BranchNotFound(format!(
+            "{}/{} (fetch the remote first)",
+            DEFAULT_REMOTE, name
+        )));
+    }
+    repo.reference_symbolic(&head_ref, &target, true, "gitflow: repair origin/HEAD")?;
+
+    info!("Set {} to {}", head_ref, target);
+    Ok(name)
+}
+
+/// Fetch a single branch from a remote, updating its remote-tracking branch.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `remote_name` - The remote to fetch from.
+/// * `branch_name` - The branch to fetch.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the remote cannot be fetched.
+///
+/// # Examples
+/// ```rust
+/// // fetch_branch(&repo, "origin", "feature")?;
+/// ```
+pub fn fetch_branch(repo: &Repository, remote_name: &str, branch_name: &str) -> Result<()> {
+    let mut remote = repo.find_remote(remote_name)?;
+    let refspec = format!(
+        "+refs/heads/{0}:refs/remotes/{1}/{0}",
+        branch_name, remote_name
+    );
+
+    with_retries(
+        &format!("Fetching {} from {}", branch_name, remote_name),
+        || {
+            let mut fetch_options = FetchOptions::new();
+            fetch_options.remote_callbacks(remote_callbacks(repo));
+            remote.fetch(&[&refspec], Some(&mut fetch_options), None)
+        },
+    )?;
+    debug!("Fetched {} from {}", branch_name, remote_name);
+    Ok(())
+}
+
+/// Fetch every configured remote, updating all remote-tracking branches.
+///
+/// A remote that cannot be reached is reported as a warning and skipped so that the
+/// remaining remotes are still fetched.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `Result<usize>` - The number of remotes fetched successfully.
+///
+/// # Examples
+/// ```rust
+/// // let fetched = fetch_all(&repo)?;
+/// ```
+pub fn fetch_all(repo: &Repository) -> Result<usize> {
+    let remotes = repo.remotes()?;
+    let mut fetched = 0;
+
+    for name in remotes.iter().flatten() {
+        let mut remote = repo.find_remote(name)?;
+
+        // An empty refspec list uses the remote's configured fetch refspecs.
+        let result = with_retries(&format!("Fetching {}", name), || {
+            let mut fetch_options = FetchOptions::new();
+            fetch_options.remote_callbacks(remote_callbacks(repo));
+            remote.fetch(&[] as &[&str], Some(&mut fetch_options), None)
+        });
+        match result {
+            Ok(()) => {
+                let stats = remote.stats();
+                info!(
+                    "Fetched {}: {} objects received",
+                    name,
+                    stats.received_objects()
+                );
+                fetched += 1;
+            }
+            Err(e) => warn!("Could not fetch {}: {}", name, e),
+        }
+    }
+
+    Ok(fetched)
+}
+
+/// Fast-forward a local branch to its upstream after a fetch.
+///
+/// A checked-out branch has its working tree updated as well; the checkout refuses to
+/// overwrite local modifications.
+///
+/// # Arguments
+///
+/// * `repo`        - A reference to the Git repository.
+/// * `branch_name` - The local branch name.
+///
+/// # Returns
+///
+/// * `Result<bool>` - True if the branch was moved, false if it has no upstream or is not
+///   behind it, or an Aborted error if it has diverged from its upstream.
+///
+/// # Examples
+/// ```rust
+/// // fast_forward_to_upstream(&repo, "feature")?;
+/// ```
+pub fn fast_forward_to_upstream(repo: &Repository, branch_name: &str) -> Result<bool> {
+    let branch = repo.find_branch(branch_name, BranchType::Local)?;
+    let Ok(upstream) = branch.upstream() else {
+        return Ok(false);
+    };
+    let local = branch.get().peel_to_commit()?.id();
+    let target = upstream.get().peel_to_commit()?;
+    let (ahead, behind) = repo.graph_ahead_behind(local, target.id())?;
+    if behind == 0 {
+        return Ok(false);
+    }
+    if ahead > 0 {
+        return Err(GitFlowError::Aborted(format!(
+            "{} has diverged from its upstream ({} ahead, {} behind); rebase or merge it first",
+            branch_name, ahead, behind
+        )));
+    }
+
+    if branch.is_head() {
+        repo.checkout_tree(target.as_object(), Some(CheckoutBuilder::new().safe()))?;
+    }
+    let upstream_name = upstream.name()?.unwrap_or("its upstream").to_string();
+    branch
+        .into_reference()
+        .set_target(target.id(), "gitflow: fast-forward to upstream")?;
+
+    info!(
+        "Fast-forwarded {} by {} commit(s) to {}",
+        branch_name, behind, upstream_name
+    );
+    Ok(true)
+}
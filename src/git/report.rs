@@ -0,0 +1,89 @@
+//! Module for cascade conflict reports.
+//!
+//! This module collects the merges of a cascade that failed, together with the files that
+//! conflict, and writes them as JSON so they can be shared and triaged by the people who own
+//! the branches.
+//!
+//! # Details
+//! Detailed documentation and example usage are provided to simplify future maintenance.
+
+use crate::configuration::Config;
+use crate::error::{GitFlowError, Result};
+use crate::git::merge::preview_conflicts;
+use git2::Repository;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// A merge of a parent branch into a child branch that failed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedMerge {
+    /// The branch being merged.
+    pub from: String,
+    /// The branch receiving the merge.
+    pub to: String,
+    /// Why the merge failed.
+    pub error: String,
+    /// The files that conflict, empty if the merge failed for another reason.
+    pub conflicts: Vec<String>,
+}
+
+impl FailedMerge {
+    /// Describe a failed merge, looking up the conflicting files.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo`   - A reference to the Git repository.
+    /// * `from`   - The branch being merged.
+    /// * `to`     - The branch receiving the merge.
+    /// * `error`  - The error the merge failed with.
+    /// * `config` - Configuration providing the merge options.
+    ///
+    /// # Returns
+    ///
+    /// * `FailedMerge` - The failed merge; conflicts are left empty if they cannot be computed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // failures.push(FailedMerge::new(&repo, "main", "feature", &e, &config));
+    /// ```
+    pub fn new(
+        repo: &Repository,
+        from: &str,
+        to: &str,
+        error: &GitFlowError,
+        config: &Config,
+    ) -> Self {
+        let conflicts = preview_conflicts(repo, from, to, config).unwrap_or_else(|e| {
+            debug!("Could not list conflicts of {} into {}: {}", from, to, e);
+            Vec::new()
+        });
+        FailedMerge {
+            from: from.to_string(),
+            to: to.to_string(),
+            error: error.to_string(),
+            conflicts,
+        }
+    }
+}
+
+/// Write the failed merges of a cascade to a JSON file.
+///
+/// # Arguments
+///
+/// * `failures` - The failed merges; an empty list records a cascade without failures.
+/// * `path`     - The destination file.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the file cannot be written.
+///
+/// # Examples
+/// ```rust
+/// // write_report(&failures, Path::new("conflicts-report.json"))?;
+/// ```
+pub fn write_report(failures: &[FailedMerge], path: &Path) -> Result<()> {
+    fs::write(path, serde_json::to_string_pretty(failures)?)?;
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//! Module for reusing recorded conflict resolutions.
+//!
+//! This module hands merge conflicts to `git rerere`, which mirrors what git itself does
+//! during a merge: conflicts seen before are resolved from the resolutions recorded in
+//! `.git/rr-cache`, and new conflicts have their preimage recorded there. Once a conflict has
+//! been resolved by hand with `git merge` and committed, git records the resolution and the
+//! next cascade hitting the same conflict resolves it without stopping.
+//!
+//! # Details
+//! Delegating to git keeps the cache format and conflict identifiers compatible with
+//! resolutions recorded outside gitflow. Git treats an existing `.git/rr-cache` as turning on
+//! its own rerere for manual merges, so gitflow never creates it: resolutions are only
+//! replayed in repositories where it already exists, for example after
+//! `git config rerere.enabled true` and a first conflicted merge.
+
+use crate::error::{GitFlowError, Result};
+use git2::Repository;
+use log::{debug, info, warn};
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Resolve the conflicts in the index from recorded resolutions.
+///
+/// Resolved files are staged; conflicts without a recorded resolution are left in place with
+/// their preimage recorded. Nothing is done if the repository has no `.git/rr-cache`.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository, with a merge in progress.
+///
+/// # Returns
+///
+/// * `Result<bool>` - True if no conflicts remain in the index afterwards.
+///
+/// # Examples
+/// ```rust
+/// // if config.use_rerere && rerere::replay(&repo)? { /* commit the merge */ }
+/// ```
+pub fn replay(repo: &Repository) -> Result<bool> {
+    let cache = cache_dir(repo);
+    if !cache.is_dir() {
+        warn!(
+            "Not replaying recorded resolutions: {} does not exist. Run 'git config rerere.enabled true' to let git record them, which also turns on rerere for manual merges.",
+            cache.display()
+        );
+        return Ok(false);
+    }
+    if let Err(e) = run_rerere(repo, &[]) {
+        warn!("Could not replay recorded resolutions: {}", e);
+        return Ok(false);
+    }
+
+    let mut index = repo.index()?;
+    index.read(true)?;
+    let resolved = !index.has_conflicts();
+    if resolved {
+        info!("Resolved all conflicts from recorded resolutions");
+    }
+    Ok(resolved)
+}
+
+/// Forget the conflicts of a merge that is being abandoned, like `git merge --abort` does.
+///
+/// Preimages recorded for the merge are dropped so a stale resolution can never be recorded
+/// from a working tree that no longer holds the merge.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Examples
+/// ```rust
+/// // rerere::clear(&repo);
+/// ```
+pub fn clear(repo: &Repository) {
+    if !cache_dir(repo).is_dir() {
+        return;
+    }
+    if let Err(e) = run_rerere(repo, &["clear"]) {
+        debug!("Could not clear rerere state: {}", e);
+    }
+}
+
+/// The directory git records conflict resolutions in.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `PathBuf` - The path of `rr-cache` in the Git directory.
+fn cache_dir(repo: &Repository) -> PathBuf {
+    repo.path().join("rr-cache")
+}
+
+/// Run `git rerere` with automatic staging of resolved files.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+/// * `args` - Arguments to pass after `rerere`.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if git cannot be run or fails.
+fn run_rerere(repo: &Repository, args: &[&str]) -> Result<()> {
+    let workdir = repo.workdir().ok_or_else(|| {
+        GitFlowError::Git(git2::Error::from_str(
+            "Cannot use rerere in a bare repository",
+        ))
+    })?;
+
+    let output = Command::new("git")
+        .args([
+            "-c",
+            "rerere.enabled=true",
+            "-c",
+            "rerere.autoUpdate=true",
+            "rerere",
+        ])
+        .args(args)
+        .current_dir(workdir)
+        .output()?;
+    for line in String::from_utf8_lossy(&output.stderr).lines() {
+        debug!("git rerere: {}", line);
+    }
+    if !output.status.success() {
+        return Err(GitFlowError::Unknown(format!(
+            "git rerere exited with {}",
+            output.status
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,157 @@
+//! Module for branch relationships shared through the repository.
+//!
+//! This module stores manual branch relationships in the repository itself, as a
+//! `relationships.json` file committed on `refs/gitflow/relationships`, so that they can be
+//! pushed and fetched like any other ref and the whole team sees the same topology.
+//!
+//! # Details
+//! Detailed documentation and example usage are provided to simplify future maintenance.
+
+use crate::error::{GitFlowError, Result};
+use git2::{ErrorCode, FileMode, Repository};
+use log::{debug, info};
+use std::collections::{BTreeMap, HashMap};
+
+/// The ref holding the shared branch relationships.
+pub const RELATIONSHIPS_REF: &str = "refs/gitflow/relationships";
+
+/// The file inside the relationships commit that stores the relationships.
+const RELATIONSHIPS_FILE: &str = "relationships.json";
+
+/// Read the branch relationships shared through the repository.
+///
+/// # Arguments
+///
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+///
+/// * `Result<HashMap<String, Vec<String>>>` - Parent branches mapped to their children; empty
+///   if no relationships have been shared yet.
+///
+/// # Examples
+/// ```rust
+/// // let shared = read_shared_relationships(&repo)?;
+/// ```
+pub fn read_shared_relationships(repo: &Repository) -> Result<HashMap<String, Vec<String>>> {
+    let reference = match repo.find_reference(RELATIONSHIPS_REF) {
+        Ok(reference) => reference,
+        Err(e) if e.code() == ErrorCode::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let tree = reference.peel_to_commit()?.tree()?;
+    let entry = tree.get_name(RELATIONSHIPS_FILE).ok_or_else(|| {
+        GitFlowError::Config(format!(
+            "{} has no {}",
+            RELATIONSHIPS_REF, RELATIONSHIPS_FILE
+        ))
+    })?;
+    let blob = repo.find_blob(entry.id())?;
+    Ok(serde_json::from_slice(blob.content())?)
+}
+
+/// Record a branch relationship in the repository.
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `parent` - The parent branch.
+/// * `child`  - The child branch.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the relationships cannot be written.
+///
+/// # Examples
+/// ```rust
+/// // add_shared_relationship(&repo, "main", "feature")?;
+/// ```
+pub fn add_shared_relationship(repo: &Repository, parent: &str, child: &str) -> Result<()> {
+    let mut relationships = read_shared_relationships(repo)?;
+    let children = relationships.entry(parent.to_string()).or_default();
+    if children.iter().any(|c| c == child) {
+        debug!("{} -> {} is already shared", parent, child);
+        return Ok(());
+    }
+    children.push(child.to_string());
+    write_shared_relationships(
+        repo,
+        &relationships,
+        &format!("Add relationship {} -> {}", parent, child),
+    )
+}
+
+/// Remove a branch relationship from the repository.
+///
+/// # Arguments
+///
+/// * `repo`   - A reference to the Git repository.
+/// * `parent` - The parent branch.
+/// * `child`  - The child branch to remove.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the relationships cannot be written.
+///
+/// # Examples
+/// ```rust
+/// // remove_shared_relationship(&repo, "main", "feature")?;
+/// ```
+pub fn remove_shared_relationship(repo: &Repository, parent: &str, child: &str) -> Result<()> {
+    let mut relationships = read_shared_relationships(repo)?;
+    let Some(children) = relationships.get_mut(parent) else {
+        return Ok(());
+    };
+    children.retain(|c| c != child);
+    if children.is_empty() {
+        relationships.remove(parent);
+    }
+    write_shared_relationships(
+        repo,
+        &relationships,
+        &format!("Remove relationship {} -> {}", parent, child),
+    )
+}
+
+/// Commit the relationships on top of the relationships ref.
+///
+/// # Arguments
+///
+/// * `repo`          - A reference to the Git repository.
+/// * `relationships` - The complete set of relationships to store.
+/// * `message`       - The commit message describing the change.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the commit cannot be created.
+fn write_shared_relationships(
+    repo: &Repository,
+    relationships: &HashMap<String, Vec<String>>,
+    message: &str,
+) -> Result<()> {
+    // Sort the parents so the stored file only changes where the relationships do.
+    let sorted: BTreeMap<&String, &Vec<String>> = relationships.iter().collect();
+    let blob = repo.blob(serde_json::to_string_pretty(&sorted)?.as_bytes())?;
+
+    let mut builder = repo.treebuilder(None)?;
+    builder.insert(RELATIONSHIPS_FILE, blob, FileMode::Blob.into())?;
+    let tree = repo.find_tree(builder.write()?)?;
+
+    let parent = match repo.find_reference(RELATIONSHIPS_REF) {
+        Ok(reference) => Some(reference.peel_to_commit()?),
+        Err(_) => None,
+    };
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    let sig = repo.signature()?;
+    repo.commit(
+        Some(RELATIONSHIPS_REF),
+        &sig,
+        &sig,
+        message,
+        &tree,
+        &parents,
+    )?;
+
+    info!("Updated {}", RELATIONSHIPS_REF);
+    Ok(())
+}
@@ -1,13 +1,15 @@
 //! Module for retrieving Git repository status.
 //!
 //! This module provides functions to obtain the status of files in the repository,
-//! including untracked files and modifications.
+//! including untracked files and modifications, and to abort an interrupted merge or rebase.
 //!
 //! # Details
 //! Enhanced documentation is provided for easier debugging and maintenance.
 
 use crate::error::{GitFlowError, Result};
-use git2::{Repository, Status, StatusOptions};
+use git2::{Oid, Repository, RepositoryState, ResetType, Status, StatusOptions};
+use log::info;
+use std::fs;
 
 /// StatusEntry represents a file's status in the repository.
 #[derive(Debug)]
@@ -21,6 +23,8 @@ pub struct StatusEntry {
 /// # Arguments
 /// * `repo`              - A reference to the Git repository.
 /// * `include_untracked` - Whether to include untracked files in the status.
+/// * `pathspec`          - Optional paths or patterns limiting the entries returned; the whole
+///   working tree if None.
 ///
 /// # Returns
 /// * `Result<Vec<StatusEntry>>` - A vector of file status entries, or an error if the operation fails.
@@ -28,10 +32,19 @@ pub struct StatusEntry {
 /// # Examples
 /// ```rust
 /// // Example: Retrieve status including untracked files.
-/// // let statuses = get_repo_status(&repo, true)?;
+/// // let statuses = get_repo_status(&repo, true, None)?;
+/// // Example: Only look at changes under services/api.
+/// // let statuses = get_repo_status(&repo, true, Some(&["services/api".to_string()]))?;
 /// ```
-pub fn get_repo_status(repo: &Repository, include_untracked: bool) -> Result<Vec<StatusEntry>> {
+pub fn get_repo_status(
+    repo: &Repository,
+    include_untracked: bool,
+    pathspec: Option<&[String]>,
+) -> Result<Vec<StatusEntry>> {
     let mut status_opts = StatusOptions::new();
+    for path in pathspec.unwrap_or_default() {
+        status_opts.pathspec(path);
+    }
     status_opts.include_untracked(include_untracked);
     status_opts.recurse_untracked_dirs(include_untracked);
     status_opts.include_unmodified(false);
@@ -51,3 +64,122 @@ pub fn get_repo_status(repo: &Repository, include_untracked: bool) -> Result<Vec
     }
     Ok(result)
 }
+
+/// Abort an in-progress merge or rebase, restoring a clean working tree.
+///
+/// Rebases are aborted through git2 so the original branch is checked out again; rebases
+/// git2 cannot open (such as those started by `git rebase`) are abandoned by returning to the
+/// branch, or for a detached rebase the commit, recorded in the rebase directory. A merge has
+/// its state cleaned up and the working tree and index reset to HEAD. Other operations, such as
+/// a bisect or cherry-pick, are left alone.
+///
+/// # Arguments
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+/// * `Result<Option<RepositoryState>>` - The state that was aborted, or None if no operation
+///   was in progress. An Aborted error is returned for an operation that is not a merge or
+///   rebase.
+///
+/// # Examples
+/// ```rust
+/// // if let Some(state) = abort_in_progress(&repo)? { ... }
+/// ```
+pub fn abort_in_progress(repo: &Repository) -> Result<Option<RepositoryState>> {
+    let state = repo.state();
+    match state {
+        RepositoryState::Clean => return Ok(None),
+        RepositoryState::Rebase
+        | RepositoryState::RebaseInteractive
+        | RepositoryState::RebaseMerge => match repo.open_rebase(None) {
+            Ok(mut rebase) => rebase.abort()?,
+            Err(_) => {
+                // The branch ref is only moved once a rebase finishes, so going back to it
+                // restores the state from before the rebase. A detached rebase records
+                // "detached HEAD" as its head name, so the original commit is used instead.
+                // Both are read before cleanup_state removes the rebase directory.
+                let read_state = |file: &str| {
+                    ["rebase-merge", "rebase-apply"]
+                        .iter()
+                        .find_map(|dir| fs::read_to_string(repo.path().join(dir).join(file)).ok())
+                        .map(|contents| contents.trim().to_string())
+                };
+                let head_name = read_state("head-name");
+                let orig_head = read_state("orig-head");
+                repo.cleanup_state()?;
+                match (head_name, orig_head) {
+                    (Some(head_name), _) if head_name.starts_with("refs/") => {
+                        repo.set_head(&head_name)?
+                    }
+                    (_, Some(orig_head)) => repo.set_head_detached(Oid::from_str(&orig_head)?)?,
+                    _ => {}
+                }
+                reset_to_head(repo)?;
+            }
+        },
+        RepositoryState::Merge => {
+            repo.cleanup_state()?;
+            reset_to_head(repo)?;
+        }
+        other => {
+            return Err(GitFlowError::Aborted(format!(
+                "Cannot abort {:?}: only merges and rebases are handled; finish or abort it with git",
+                other
+            )));
+        }
+    }
+    info!("Aborted {:?}", state);
+    Ok(Some(state))
+}
+
+/// Reset the index and working tree to the commit HEAD points at.
+///
+/// # Arguments
+/// * `repo` - A reference to the Git repository.
+///
+/// # Returns
+/// * `Result<()>` - Ok on success, or an error if the reset fails.
+fn reset_to_head(repo: &Repository) -> Result<()> {
+    let head = repo.head()?.peel_to_commit()?;
+    repo.reset(head.as_object(), ResetType::Hard, None)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::testing::TestRepo;
+
+    #[test]
+    fn abort_returns_a_detached_rebase_to_its_original_commit() {
+        let test = TestRepo::new();
+        let base = test.commit_file("file.txt", "base\n", "base");
+        let original = test.commit_file("file.txt", "work\n", "work");
+
+        // Leave the state a `git rebase` of a detached HEAD stops in, which git2 cannot open.
+        test.repo.set_head_detached(base).unwrap();
+        let state_dir = test.repo.path().join("rebase-merge");
+        fs::create_dir_all(&state_dir).unwrap();
+        fs::write(state_dir.join("head-name"), "detached HEAD\n").unwrap();
+        fs::write(state_dir.join("orig-head"), format!("{}\n", original)).unwrap();
+
+        let aborted = abort_in_progress(&test.repo).unwrap();
+
+        assert_eq!(aborted, Some(RepositoryState::RebaseMerge));
+        assert!(test.repo.head_detached().unwrap());
+        assert_eq!(test.repo.head().unwrap().target(), Some(original));
+        assert_eq!(test.repo.state(), RepositoryState::Clean);
+    }
+
+    #[test]
+    fn abort_refuses_operations_other_than_merge_and_rebase() {
+        let test = TestRepo::new();
+        test.commit_file("file.txt", "base\n", "base");
+        fs::write(test.repo.path().join("BISECT_LOG"), "").unwrap();
+
+        let result = abort_in_progress(&test.repo);
+
+        assert!(matches!(result, Err(GitFlowError::Aborted(_))));
+        assert_eq!(test.repo.state(), RepositoryState::Bisect);
+    }
+}
@@ -0,0 +1,99 @@
+//! Module for Git tag operations.
+//!
+//! This module provides functionality to create annotated or lightweight tags on a branch tip
+//! and to push them to the remote.
+//!
+//! # Details
+//! Detailed documentation and example usage are provided to simplify future maintenance.
+
+use crate::error::{GitFlowError, Result};
+use crate::git::remote::{DEFAULT_REMOTE, push_error, remote_callbacks, with_retries};
+use git2::{Oid, PushOptions, Repository, Signature};
+use log::info;
+
+/// Create a tag pointing at the tip of a branch.
+///
+/// An annotated tag is created when a message is given, a lightweight tag otherwise.
+///
+/// # Arguments
+///
+/// * `repo`    - A reference to the Git repository.
+/// * `name`    - The tag name.
+/// * `target`  - The branch whose tip is tagged.
+/// * `message` - Optional tag message; creates an annotated tag when present.
+/// * `signer`  - The tagger identity recorded on annotated tags.
+/// * `force`   - Overwrite an existing tag with the same name.
+///
+/// # Returns
+///
+/// * `Result<Oid>` - The id of the tag object (or of the tagged commit for lightweight tags).
+///
+/// # Examples
+/// ```rust
+/// // let oid = create_tag(&repo, "v1.0.0", "release/1.0", Some("Release 1.0"), &sig, false)?;
+/// ```
+pub fn create_tag(
+    repo: &Repository,
+    name: &str,
+    target: &str,
+    message: Option<&str>,
+    signer: &Signature,
+    force: bool,
+) -> Result<Oid> {
+    if !force && repo.find_reference(&format!("refs/tags/{}", name)).is_ok() {
+        return Err(GitFlowError::Aborted(format!(
+            "Tag '{}' already exists. Use --force to overwrite it.",
+            name
+        )));
+    }
+
+    let object = repo
+        .revparse_single(&format!("refs/heads/{}", target))
+        .map_err(|_| GitFlowError::BranchNotFound(target.to_string()))?;
+
+    let oid = match message {
+        Some(message) => repo.tag(name, &object, signer, message, force)?,
+        None => repo.tag_lightweight(name, &object, force)?,
+    };
+
+    info!("Created tag {} on {}", name, target);
+    Ok(oid)
+}
+
+/// Push a tag to the default remote.
+///
+/// # Arguments
+///
+/// * `repo`  - A reference to the Git repository.
+/// * `name`  - The tag name.
+/// * `force` - Overwrite the tag on the remote if it already exists.
+///
+/// # Returns
+///
+/// * `Result<()>` - Ok on success, or an error if the push fails.
+///
+/// # Examples
+/// ```rust
+/// // push_tag(&repo, "v1.0.0", false)?;
+/// ```
+pub fn push_tag(repo: &Repository, name: &str, force: bool) -> Result<()> {
+    let mut remote = repo.find_remote(DEFAULT_REMOTE)?;
+    let refspec = format!(
+        "{}refs/tags/{1}:refs/tags/{1}",
+        if force { "+" } else { "" },
+        name
+    );
+
+    with_retries(
+        &format!("Pushing tag {} to {}", name, DEFAULT_REMOTE),
+        || {
+            let mut push_options = PushOptions::new();
+            push_options.remote_callbacks(remote_callbacks(repo));
+            remote.push(&[&refspec], Some(&mut push_options))
+        },
+    )
+    .map_err(push_error)?;
+
+    info!("Pushed tag {} to {}", name, DEFAULT_REMOTE);
+    Ok(())
+}
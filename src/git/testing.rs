@@ -0,0 +1,103 @@
+//! Helpers for unit tests that need a scratch Git repository.
+//!
+//! Each `TestRepo` is created in its own directory under the system temp directory, with
+//! `main` as the initial branch and a fixed user identity, and is removed when dropped.
+
+use git2::build::CheckoutBuilder;
+use git2::{Oid, Repository, RepositoryInitOptions, Signature, Time};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_REPO: AtomicUsize = AtomicUsize::new(0);
+
+/// A repository in a temporary directory, deleted on drop
+pub struct TestRepo {
+    pub repo: Repository,
+    dir: PathBuf,
+}
+
+impl TestRepo {
+    /// Create an empty repository on an unborn `main` branch.
+    pub fn new() -> Self {
+        let dir = std::env::temp_dir().join(format!(
+            "gitflow-test-{}-{}",
+            process::id(),
+            NEXT_REPO.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        let repo = Repository::init_opts(&dir, RepositoryInitOptions::new().initial_head("main"))
+            .expect("init test repository");
+        let mut config = repo.config().expect("open repository config");
+        config.set_str("user.name", "Test Author").unwrap();
+        config.set_str("user.email", "author@example.com").unwrap();
+        TestRepo { repo, dir }
+    }
+
+    /// Write a file in the working directory, creating parent directories.
+    pub fn write(&self, path: &str, contents: &str) {
+        let path = self.dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    /// Write a file and commit it on the current branch as the configured user.
+    pub fn commit_file(&self, path: &str, contents: &str, message: &str) -> Oid {
+        let sig = self.repo.signature().unwrap();
+        self.commit_file_as(path, contents, message, &sig)
+    }
+
+    /// Write a file and commit it with an author and committer date `seconds` after the epoch.
+    pub fn commit_file_at(&self, path: &str, contents: &str, message: &str, seconds: i64) -> Oid {
+        let sig =
+            Signature::new("Test Author", "author@example.com", &Time::new(seconds, 0)).unwrap();
+        self.commit_file_as(path, contents, message, &sig)
+    }
+
+    fn commit_file_as(&self, path: &str, contents: &str, message: &str, sig: &Signature) -> Oid {
+        self.write(path, contents);
+        let mut index = self.repo.index().unwrap();
+        index.add_path(Path::new(path)).unwrap();
+        index.write().unwrap();
+        let tree = self.repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let parent = self
+            .repo
+            .head()
+            .ok()
+            .map(|head| head.peel_to_commit().unwrap());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        self.repo
+            .commit(Some("HEAD"), sig, sig, message, &tree, &parents)
+            .unwrap()
+    }
+
+    /// Create a branch at the current HEAD.
+    pub fn branch(&self, name: &str) {
+        let head = self.repo.head().unwrap().peel_to_commit().unwrap();
+        self.repo.branch(name, &head, false).unwrap();
+    }
+
+    /// Check out an existing branch, discarding changes in the working directory.
+    pub fn checkout(&self, name: &str) {
+        self.repo.set_head(&format!("refs/heads/{}", name)).unwrap();
+        self.repo
+            .checkout_head(Some(CheckoutBuilder::new().force()))
+            .unwrap();
+    }
+
+    /// The commit a branch points at.
+    pub fn tip(&self, name: &str) -> Oid {
+        self.repo
+            .find_reference(&format!("refs/heads/{}", name))
+            .unwrap()
+            .target()
+            .unwrap()
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
@@ -14,9 +14,12 @@ mod error;
 mod git;
 mod utils;
 
-use cli::Cli;
-use commands::{cascade, config, create, show};
-use error::Result;
+use cli::{Cli, ErrorFormat};
+use commands::{
+    abort, cascade, commit, compare, config, create, describe, diff, doctor, open, parent, preview,
+    push, reparent, save, show, stale, status, switch, tag, validate,
+};
+use error::{GitFlowError, Result};
 
 use clap::Parser;
 use git2::Repository;
@@ -26,12 +29,28 @@ use log::error;
 fn main() {
     // Parse command line arguments.
     let cli = Cli::parse();
-    utils::init_logger(cli.verbose);
+    let color = cli.color.enabled();
+    colored::control::set_override(color);
+    utils::init_logger(cli.verbose, color);
+    utils::set_assume_no(cli.assume_no);
+    git::set_no_wait(cli.no_wait);
+    let error_format = cli.error_format;
 
     // Run the application logic and handle any errors.
     if let Err(e) = run(cli) {
-        error!("Error: {}", e);
-        std::process::exit(1);
+        let exit_code = 1;
+        match error_format {
+            ErrorFormat::Human => error!("Error: {}", e),
+            ErrorFormat::Json => eprintln!(
+                "{}",
+                serde_json::json!({
+                    "error_kind": e.kind(),
+                    "message": e.to_string(),
+                    "exit_code": exit_code,
+                })
+            ),
+        }
+        std::process::exit(exit_code);
     }
 }
 
@@ -44,48 +63,134 @@ fn main() {
 /// # Returns
 ///
 /// * `Result<()>` - Returns Ok on success, or an error on failure.
-fn run(cli: cli::Cli) -> Result<()> {
-    match &cli.command {
-        cli::Commands::Config {
-            default_base,
-            detection_strategy,
-            add_relationship,
-            remove_relationship,
-        } => {
-            return config::handle_config(
-                default_base.as_deref(),
-                *detection_strategy,
-                add_relationship.as_deref(),
-                remove_relationship.as_deref(),
-            );
-        }
-        _ => {}
+fn run(mut cli: cli::Cli) -> Result<()> {
+    if let cli::Commands::Config(args) = &cli.command {
+        return config::handle_config(args);
     }
 
+    // Fill in configured per-command defaults for flags not given on the command line.
+    let settings = configuration::Config::load()?;
+    cli.command
+        .apply_defaults(&settings.command_defaults, cli.assume_no)?;
+    git::configure_network(
+        settings.git_network_timeout_secs(),
+        settings.git_network_retries(),
+    )?;
+
     // Open the Git repository located in the current directory.
-    let repo = Repository::open(".")?;
+    let mut repo = Repository::open(".")?;
 
     // Dispatch based on the user's command.
     match cli.command {
-        cli::Commands::Create { name, parent } => {
-            create::handle_new_branch(&repo, &name, parent.as_deref()).map_err(|e| {
-                println!("Error: {}", e);
-                e
-            })?;
-        }
-        cli::Commands::Cascade { yes, strategy } => {
-            cascade::handle_cascade(&repo, yes, strategy).map_err(|e| {
-                println!("Error: {}", e);
-                e
-            })?;
-        }
-        cli::Commands::Show { strategy } => {
-            show::handle_show(&repo, strategy).map_err(|e| {
-                println!("Error: {}", e);
-                e
-            })?;
-        }
-        cli::Commands::Config { .. } => {
+        cli::Commands::Create {
+            name,
+            parent,
+            parent_auto,
+            no_hooks,
+            dry_run,
+        } => {
+            create::handle_new_branch(
+                &repo,
+                &name,
+                parent.as_deref(),
+                parent_auto,
+                no_hooks,
+                dry_run,
+            )?;
+        }
+        cli::Commands::Cascade(args) => {
+            if args.yes && cli.assume_no {
+                return Err(GitFlowError::Config(
+                    "--yes and --assume-no cannot be used together".to_string(),
+                ));
+            }
+            cascade::handle_cascade(&repo, &args)?;
+        }
+        cli::Commands::Show(args) => {
+            show::handle_show(&repo, &args)?;
+        }
+        cli::Commands::Compare {
+            strategies,
+            ascii,
+            only,
+        } => {
+            compare::handle_compare(&repo, &strategies, ascii, &only)?;
+        }
+        cli::Commands::Commit(args) => {
+            commit::handle_commit(&repo, &args)?;
+        }
+        cli::Commands::Preview { from, to } => {
+            preview::handle_preview(&repo, &from, &to)?;
+        }
+        cli::Commands::Push {
+            all,
+            branch,
+            force,
+            force_with_lease,
+            rebase,
+        } => {
+            push::handle_push(
+                &repo,
+                all,
+                branch.as_deref(),
+                force,
+                force_with_lease,
+                rebase,
+            )?;
+        }
+        cli::Commands::Diff { branch, base, stat } => {
+            diff::handle_diff(&repo, branch.as_deref(), base.as_deref(), stat)?;
+        }
+        cli::Commands::Reparent { new_parent, branch } => {
+            reparent::handle_reparent(&repo, branch.as_deref(), &new_parent)?;
+        }
+        cli::Commands::Describe { text, branch } => {
+            describe::handle_describe(&repo, branch.as_deref(), &text)?;
+        }
+        cli::Commands::Abort => {
+            abort::handle_abort(&repo)?;
+        }
+        cli::Commands::Switch { query } => {
+            switch::handle_switch(&mut repo, query.as_deref())?;
+        }
+        cli::Commands::Doctor => {
+            doctor::handle_doctor(&repo)?;
+        }
+        cli::Commands::Tag {
+            name,
+            message,
+            branch,
+            push,
+            force,
+        } => {
+            tag::handle_tag(
+                &repo,
+                &name,
+                message.as_deref(),
+                branch.as_deref(),
+                push,
+                force,
+            )?;
+        }
+        cli::Commands::Open { branch, compare } => {
+            open::handle_open(&repo, branch.as_deref(), compare)?;
+        }
+        cli::Commands::Parent { branch, strategy } => {
+            parent::handle_parent(&repo, &branch, strategy)?;
+        }
+        cli::Commands::Save { message, push } => {
+            save::handle_save(&repo, &message, push)?;
+        }
+        cli::Commands::Stale { days } => {
+            stale::handle_stale(&repo, days)?;
+        }
+        cli::Commands::Status { paths } => {
+            status::handle_status(&repo, &paths)?;
+        }
+        cli::Commands::Validate { format } => {
+            validate::handle_validate(&repo, format)?;
+        }
+        cli::Commands::Config(_) => {
             // Already handled above.
         }
     }
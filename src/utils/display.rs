@@ -1,14 +1,47 @@
 //! Module for displaying output.
 //!
 //! This module provides utilities such as prompting the user for confirmation,
-//! formatting branch names and PR links, and printing the branch hierarchy.
+//! formatting branch names and PR links, and rendering the branch hierarchy.
 //!
 //! # Details
 //! Detailed examples and descriptions are provided to facilitate future code maintenance.
 
 use colored::{ColoredString, Colorize};
 use std::collections::HashMap;
+use std::env;
 use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether confirmations are answered "no" without prompting.
+static ASSUME_NO: AtomicBool = AtomicBool::new(false);
+
+/// Answer every confirmation prompt with "no" without reading stdin
+///
+/// # Arguments
+/// * `enabled` - True to decline all confirmations automatically.
+///
+/// # Examples
+/// ```rust
+/// // Example:
+/// // set_assume_no(cli.assume_no);
+/// ```
+pub fn set_assume_no(enabled: bool) {
+    ASSUME_NO.store(enabled, Ordering::Relaxed);
+}
+
+/// Check whether confirmations are being declined automatically
+///
+/// # Returns
+/// * `bool` - True if `--assume-no` is in effect.
+///
+/// # Examples
+/// ```rust
+/// // Example:
+/// // if assume_no() { ... }
+/// ```
+pub fn assume_no() -> bool {
+    ASSUME_NO.load(Ordering::Relaxed)
+}
 
 /// Prompt the user for confirmation with a yes/no question
 ///
@@ -16,7 +49,8 @@ use std::io::{self, Write};
 /// * `message` - The prompt message to display.
 ///
 /// # Returns
-/// * `io::Result<bool>` - Returns true if the user confirms with 'y', false otherwise.
+/// * `io::Result<bool>` - Returns true if the user confirms with 'y', false otherwise
+///   (always false when `--assume-no` is in effect).
 ///
 /// # Examples
 /// ```rust
@@ -24,6 +58,11 @@ use std::io::{self, Write};
 /// // if prompt_confirmation("Proceed with action?")? { ... }
 /// ```
 pub fn prompt_confirmation(message: &str) -> io::Result<bool> {
+    if assume_no() {
+        println!("{} [y/N]: n (--assume-no)", message);
+        return Ok(false);
+    }
+
     print!("{} [y/N]: ", message);
     io::stdout().flush()?;
 
@@ -33,6 +72,41 @@ pub fn prompt_confirmation(message: &str) -> io::Result<bool> {
     Ok(input.trim().to_lowercase() == "y")
 }
 
+/// Prompt the user to pick one of a numbered list of choices
+///
+/// # Arguments
+/// * `message` - The prompt message to display.
+/// * `count`   - The number of choices, numbered from 1.
+///
+/// # Returns
+/// * `io::Result<Option<usize>>` - The zero-based index of the chosen entry, or None if the
+///   input is not a valid choice (always None when `--assume-no` is in effect).
+///
+/// # Examples
+/// ```rust
+/// // Example:
+/// // if let Some(index) = prompt_selection("Switch to", branches.len())? { ... }
+/// ```
+pub fn prompt_selection(message: &str, count: usize) -> io::Result<Option<usize>> {
+    if assume_no() {
+        println!("{} [1-{}]: (--assume-no)", message, count);
+        return Ok(None);
+    }
+
+    print!("{} [1-{}]: ", message, count);
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(input
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|n| (1..=count).contains(n))
+        .map(|n| n - 1))
+}
+
 /// Format a branch name with color based on whether it's the current branch
 ///
 /// # Arguments
@@ -73,98 +147,262 @@ pub fn format_pr_link(number: u64, url: &str) -> ColoredString {
     format!(" [PR #{}]({})", number, url).blue()
 }
 
-/// Print the branch tree as a hierarchy
+/// Maximum number of characters of a branch description shown in the hierarchy.
+const DESCRIPTION_WIDTH: usize = 50;
+
+/// Glyphs used to draw the branch hierarchy
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TreeGlyphs {
+    /// Connector for a branch that has later siblings.
+    pub tee: &'static str,
+    /// Connector for the last branch among its siblings.
+    pub corner: &'static str,
+    /// Indentation below a branch that has later siblings.
+    pub pipe: &'static str,
+    /// Indentation below the last branch among its siblings.
+    pub blank: &'static str,
+    /// Marker for truncated text.
+    pub ellipsis: &'static str,
+}
+
+/// Box-drawing glyphs, used by default.
+pub const UNICODE_GLYPHS: TreeGlyphs = TreeGlyphs {
+    tee: "├── ",
+    corner: "└── ",
+    pipe: "│   ",
+    blank: "    ",
+    ellipsis: "…",
+};
+
+/// Plain ASCII glyphs for terminals that cannot render box-drawing characters.
+pub const ASCII_GLYPHS: TreeGlyphs = TreeGlyphs {
+    tee: "|-- ",
+    corner: "`-- ",
+    pipe: "|   ",
+    blank: "    ",
+    ellipsis: "...",
+};
+
+/// Choose the tree glyphs to use
+///
+/// ASCII glyphs are used when requested, or when the locale (`LC_ALL`, `LC_CTYPE` or `LANG`,
+/// whichever is set first) does not advertise UTF-8.
+///
+/// # Arguments
+/// * `ascii` - Force ASCII glyphs.
+///
+/// # Returns
+/// * `TreeGlyphs` - The glyph set to draw the hierarchy with.
+///
+/// # Examples
+/// ```rust
+/// // Example:
+/// // let glyphs = select_glyphs(false);
+/// ```
+pub fn select_glyphs(ascii: bool) -> TreeGlyphs {
+    if ascii {
+        return ASCII_GLYPHS;
+    }
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|value| !value.is_empty());
+    match locale {
+        Some(locale) => {
+            let locale = locale.to_lowercase();
+            if locale.contains("utf-8") || locale.contains("utf8") {
+                UNICODE_GLYPHS
+            } else {
+                ASCII_GLYPHS
+            }
+        }
+        None => UNICODE_GLYPHS,
+    }
+}
+
+/// Per-branch details displayed alongside the branch hierarchy
+#[derive(Debug, Default)]
+pub struct BranchDetails {
+    /// A mapping of branch names to PR number and URL.
+    pub pr_info: HashMap<String, (u64, String)>,
+    /// A mapping of branch names to their first commit message line.
+    pub commit_messages: HashMap<String, String>,
+    /// A mapping of branch names to their `branch.<name>.description`.
+    pub descriptions: HashMap<String, String>,
+    /// A mapping of branch names to the abbreviated hash of their tip commit.
+    pub commit_hashes: HashMap<String, String>,
+}
+
+/// Format a branch description for display, truncated to a single short line
+///
+/// # Arguments
+/// * `description` - The branch description.
+/// * `glyphs`      - The glyph set providing the truncation marker.
+///
+/// # Returns
+/// * `ColoredString` - The formatted description.
+///
+/// # Examples
+/// ```rust
+/// // Example:
+/// // let text = format_description("Adds the new login flow", &UNICODE_GLYPHS);
+/// ```
+pub fn format_description(description: &str, glyphs: &TreeGlyphs) -> ColoredString {
+    let line = description.lines().next().unwrap_or("").trim();
+    let text = if line.chars().count() > DESCRIPTION_WIDTH {
+        let truncated: String = line.chars().take(DESCRIPTION_WIDTH - 1).collect();
+        format!("{}{}", truncated, glyphs.ellipsis)
+    } else {
+        line.to_string()
+    };
+    format!(" - {}", text).dimmed()
+}
+
+/// Render the branch tree as a hierarchy, one line per branch
+///
+/// Branches nested deeper than `max_depth` are not rendered; a "max depth exceeded" line is
+/// shown in their place.
 ///
 /// # Arguments
 /// * `tree`            - A mapping of parent branch names to their child branches.
 /// * `root_branches`   - A list of branches with no parent.
 /// * `current_branch`  - The current checked-out branch name.
-/// * `pr_info`         - A mapping of branch names to PR information tuples.
-/// * `commit_messages` - A mapping of branch names to their first commit message line.
+/// * `details`         - PR links, commit messages and descriptions shown next to each branch.
+/// * `glyphs`          - The glyphs used to draw the tree.
+/// * `max_depth`       - The deepest level rendered, counting root branches as depth 0.
 ///
 /// # Returns
-/// * None
+/// * `String` - The rendered hierarchy, with each line terminated by a newline.
 ///
 /// # Examples
 /// ```rust
 /// // Example:
-/// // print_branch_hierarchy(&branch_tree, &roots, "main", &pr_info, &commit_msgs);
+/// // print!("{}", render_branch_hierarchy(&branch_tree, &roots, "main", &details, &UNICODE_GLYPHS, 500));
 /// ```
-pub fn print_branch_hierarchy(
+pub fn render_branch_hierarchy(
     tree: &HashMap<String, Vec<String>>,
     root_branches: &[String],
     current_branch: &str,
-    pr_info: &HashMap<String, (u64, String)>,
-    commit_messages: &HashMap<String, String>,
-) {
-    // Helper function to print branch tree recursively
-    fn print_branch_tree(
+    details: &BranchDetails,
+    glyphs: &TreeGlyphs,
+    max_depth: usize,
+) -> String {
+    let renderer = TreeRenderer {
+        tree,
+        current_branch,
+        details,
+        glyphs,
+        max_depth,
+    };
+
+    // Render the tree starting from root branches
+    let mut out = String::new();
+    let count = root_branches.len();
+    for (i, branch) in root_branches.iter().enumerate() {
+        out.push_str(&renderer.render_branch_tree(branch, "", i == count - 1, 0));
+    }
+    out
+}
+
+/// Shared state for rendering the branch hierarchy recursively
+struct TreeRenderer<'a> {
+    tree: &'a HashMap<String, Vec<String>>,
+    current_branch: &'a str,
+    details: &'a BranchDetails,
+    glyphs: &'a TreeGlyphs,
+    max_depth: usize,
+}
+
+impl TreeRenderer<'_> {
+    /// Render a branch and its descendants
+    ///
+    /// # Arguments
+    /// * `branch`  - The branch to render.
+    /// * `prefix`  - The indentation drawn before the branch connector.
+    /// * `is_last` - Whether the branch is the last among its siblings.
+    /// * `depth`   - Depth of the branch in the hierarchy, 0 for root branches.
+    ///
+    /// # Returns
+    /// * `String` - The rendered lines for the branch and its descendants.
+    fn render_branch_tree(
+        &self,
         branch: &str,
-        tree: &HashMap<String, Vec<String>>,
-        current_branch: &str,
-        pr_info: &HashMap<String, (u64, String)>,
-        commit_messages: &HashMap<String, String>,
         prefix: &str,
         is_last: bool,
-    ) {
+        depth: usize,
+    ) -> String {
+        let (details, glyphs) = (self.details, self.glyphs);
+        let branch_symbol = if is_last { glyphs.corner } else { glyphs.tee };
+
+        // Stop before a pathological hierarchy exhausts the stack.
+        if depth > self.max_depth {
+            return format!(
+                "{}{}{}\n",
+                prefix,
+                branch_symbol,
+                format!("max depth {} exceeded", self.max_depth).red()
+            );
+        }
+
         // Format branch name with PR link if available
-        let branch_display = format_branch_name(branch, branch == current_branch);
+        let branch_display = format_branch_name(branch, branch == self.current_branch);
 
-        let pr_display = if let Some((number, url)) = pr_info.get(branch) {
+        let hash_display = if let Some(hash) = details.commit_hashes.get(branch) {
+            format!(" {}", hash).dimmed()
+        } else {
+            "".normal()
+        };
+
+        let pr_display = if let Some((number, url)) = details.pr_info.get(branch) {
             format_pr_link(*number, url)
         } else {
             "".normal()
         };
 
         // Get commit message if available
-        let commit_display = if let Some(message) = commit_messages.get(branch) {
+        let commit_display = if let Some(message) = details.commit_messages.get(branch) {
             format!(" \"{}\"", message).yellow()
         } else {
             "".normal()
         };
 
-        // Format branch line
-        let branch_symbol = if is_last { "└── " } else { "├── " };
+        // Get branch description if available
+        let description_display = if let Some(description) = details.descriptions.get(branch) {
+            format_description(description, glyphs)
+        } else {
+            "".normal()
+        };
 
-        println!(
-            "{}{}{}{}{}",
-            prefix, branch_symbol, branch_display, pr_display, commit_display
+        // Format branch line
+        let mut out = format!(
+            "{}{}{}{}{}{}{}\n",
+            prefix,
+            branch_symbol,
+            branch_display,
+            hash_display,
+            pr_display,
+            commit_display,
+            description_display
         );
 
         // Process children
-        if let Some(children) = tree.get(branch) {
+        if let Some(children) = self.tree.get(branch) {
             let new_prefix = if is_last {
-                format!("{}    ", prefix)
+                format!("{}{}", prefix, glyphs.blank)
             } else {
-                format!("{}│   ", prefix)
+                format!("{}{}", prefix, glyphs.pipe)
             };
 
             let count = children.len();
             for (i, child) in children.iter().enumerate() {
-                print_branch_tree(
+                out.push_str(&self.render_branch_tree(
                     child,
-                    tree,
-                    current_branch,
-                    pr_info,
-                    commit_messages,
                     &new_prefix,
                     i == count - 1,
-                );
+                    depth + 1,
+                ));
             }
         }
-    }
-
-    // Print the tree starting from root branches
-    let count = root_branches.len();
-    for (i, branch) in root_branches.iter().enumerate() {
-        print_branch_tree(
-            branch,
-            tree,
-            current_branch,
-            pr_info,
-            commit_messages,
-            "",
-            i == count - 1,
-        );
+        out
     }
 }
@@ -12,6 +12,7 @@ use tracing_subscriber::EnvFilter;
 ///
 /// # Arguments
 /// * `verbosity` - The verbosity level (0 for Info, 1 for Debug, and >=2 for Trace).
+/// * `color`     - Whether log lines may contain ANSI color codes.
 ///
 /// # Returns
 /// * None
@@ -19,9 +20,9 @@ use tracing_subscriber::EnvFilter;
 /// # Examples
 /// ```rust
 /// // Initialize logger with Debug verbosity.
-/// init_logger(1);
+/// init_logger(1, true);
 /// ```
-pub fn init_logger(verbosity: u8) {
+pub fn init_logger(verbosity: u8, color: bool) {
     let log_level = match verbosity {
         0 => LevelFilter::Info,
         1 => LevelFilter::Debug,
@@ -33,5 +34,6 @@ pub fn init_logger(verbosity: u8) {
         .with_env_filter(
             EnvFilter::from_default_env().add_directive(log_level.to_string().parse().unwrap()),
         )
+        .with_ansi(color)
         .init();
 }
@@ -1,5 +1,8 @@
 pub mod display;
 pub mod logger;
 
-pub use display::{print_branch_hierarchy, prompt_confirmation};
+pub use display::{
+    BranchDetails, assume_no, prompt_confirmation, prompt_selection, render_branch_hierarchy,
+    select_glyphs, set_assume_no,
+};
 pub use logger::init_logger;